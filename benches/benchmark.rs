@@ -1,36 +1,197 @@
-use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use std::thread;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
 use pqsign::{
+    composite::Composite,
+    ecdsa::{EcdsaP256, EcdsaP384},
     eddsa::Eddsa,
-    falcon::{Degree, Falcon},
+    falcon::{Degree, Falcon, SharedFalcon},
+    mldsa::{MlDsa44, MlDsa65, MlDsa87, SigningMode},
+    rsapss::{RsaPss2048, RsaPss3072},
     signing_scheme::SigningScheme,
+    slhdsa::{SlhDsaSha2_128f, SlhDsaSha2_128s, SlhDsaShake128f, SlhDsaShake128s},
 };
 
-fn bench_scheme<S: SigningScheme>(c: &mut Criterion, mut scheme: S) {
-    c.bench_function("keygen", |b| b.iter(|| scheme.keygen()));
+fn bench_scheme<S: SigningScheme>(c: &mut Criterion, name: &str, mut scheme: S) {
+    let mut group = c.benchmark_group(name);
+
+    group.bench_function("keygen", |b| b.iter(|| scheme.keygen()));
 
-    let (sk, pk) = scheme.keygen();
+    let (sk, pk) = scheme.keygen().unwrap();
     let m = "Hello World!";
 
-    c.bench_with_input(BenchmarkId::new("sign", m), &(&sk, m), |b, &(sk, m)| {
+    group.bench_with_input(BenchmarkId::new("sign", m), &(&sk, m), |b, &(sk, m)| {
         b.iter(|| scheme.sign(sk, m.as_bytes()))
     });
 
-    let t = scheme.sign(&sk, m.as_bytes());
+    let t = scheme.sign(&sk, m.as_bytes()).unwrap();
 
-    c.bench_with_input(
+    group.bench_with_input(
         BenchmarkId::new("verify", m),
         &(&pk, m, &t),
         |b, &(pk, m, t)| b.iter(|| scheme.verify(pk, m.as_bytes(), t)),
     );
+
+    group.finish();
+}
+
+/// `verify_batch` over `n` signatures from distinct keys, against `n` separate `verify` calls.
+fn bench_batch<S: SigningScheme>(c: &mut Criterion, name: &str, mut scheme: S, n: usize) {
+    let mut group = c.benchmark_group(name);
+
+    let m = "Hello World!".as_bytes();
+    let signed: Vec<_> = (0..n)
+        .map(|_| {
+            let (sk, pk) = scheme.keygen().unwrap();
+            let t = scheme.sign(&sk, m).unwrap();
+            (pk, t)
+        })
+        .collect();
+    let items: Vec<_> = signed.iter().map(|(pk, t)| (pk, m, t)).collect();
+
+    group.bench_with_input(BenchmarkId::new("verify", n), &items, |b, items| {
+        b.iter(|| {
+            for (pk, m, t) in items {
+                scheme.verify(pk, m, t).unwrap();
+            }
+        })
+    });
+
+    group.bench_with_input(BenchmarkId::new("verify_batch", n), &items, |b, items| {
+        b.iter(|| scheme.verify_batch(items))
+    });
+
+    group.finish();
 }
 
 fn bench_eddsa(c: &mut Criterion) {
-    bench_scheme(c, Eddsa);
+    bench_scheme(c, "eddsa", Eddsa::new());
+}
+
+fn bench_ecdsa(c: &mut Criterion) {
+    bench_scheme(c, "ecdsa-p256", EcdsaP256::new());
+    bench_scheme(c, "ecdsa-p384", EcdsaP384::new());
+}
+
+fn bench_rsapss(c: &mut Criterion) {
+    bench_scheme(c, "rsa-pss-2048", RsaPss2048::new());
+    bench_scheme(c, "rsa-pss-3072", RsaPss3072::new());
 }
 
 fn bench_falcon(c: &mut Criterion) {
-    bench_scheme(c, Falcon::new(Degree::F512, Some("seed".as_bytes())));
+    bench_scheme(
+        c,
+        "falcon-512",
+        Falcon::new(Degree::F512, Some("seed".as_bytes())),
+    );
+}
+
+fn bench_mldsa(c: &mut Criterion) {
+    bench_scheme(c, "ml-dsa-44", MlDsa44::new());
+    bench_scheme(c, "ml-dsa-65", MlDsa65::new());
+    bench_scheme(c, "ml-dsa-87", MlDsa87::new());
+    bench_scheme(
+        c,
+        "ml-dsa-65-deterministic",
+        MlDsa65::new().with_mode(SigningMode::Deterministic),
+    );
+}
+
+fn bench_slhdsa(c: &mut Criterion) {
+    bench_scheme(c, "slh-dsa-sha2-128s", SlhDsaSha2_128s::new());
+    bench_scheme(c, "slh-dsa-sha2-128f", SlhDsaSha2_128f::new());
+    bench_scheme(c, "slh-dsa-shake-128s", SlhDsaShake128s::new());
+    bench_scheme(c, "slh-dsa-shake-128f", SlhDsaShake128f::new());
+}
+
+fn bench_composite(c: &mut Criterion) {
+    bench_scheme(
+        c,
+        "ed25519+falcon-512",
+        Composite::new(
+            Eddsa::new(),
+            Falcon::new(Degree::F512, Some("seed".as_bytes())),
+        ),
+    );
+}
+
+fn bench_verify_batch(c: &mut Criterion) {
+    bench_batch(
+        c,
+        "falcon-512-batch",
+        Falcon::new(Degree::F512, Some("seed".as_bytes())),
+        64,
+    );
+}
+
+fn bench_falcon_expanded(c: &mut Criterion) {
+    let mut falcon = Falcon::new(Degree::F512, Some("seed".as_bytes()));
+    let (sk, _) = falcon.keygen().unwrap();
+    let esk = falcon.expand(&sk).unwrap();
+    let m = "Hello World!";
+
+    let mut group = c.benchmark_group("falcon-sign-paths");
+
+    group.bench_function("expand", |b| b.iter(|| falcon.expand(&sk)));
+
+    group.bench_with_input(BenchmarkId::new("sign", m), &m, |b, m| {
+        b.iter(|| falcon.sign(&sk, m.as_bytes()))
+    });
+
+    group.bench_with_input(BenchmarkId::new("sign_expanded", m), &m, |b, m| {
+        b.iter(|| falcon.sign_expanded(&esk, m.as_bytes()))
+    });
+
+    group.finish();
+}
+
+/// Signing `n` messages with one `SharedFalcon` from 1, 2, 4, .. threads up to the core count.
+fn bench_falcon_shared(c: &mut Criterion) {
+    let n = 256;
+    let cores = thread::available_parallelism().map_or(1, |n| n.get());
+    let shared = SharedFalcon::from(Falcon::new(Degree::F512, Some("seed".as_bytes())));
+    let mut falcon = Falcon::new(Degree::F512, Some("seed".as_bytes()));
+    let (sk, _) = falcon.keygen().unwrap();
+    let esk = shared.expand(&sk).unwrap();
+    let (shared, esk) = (&shared, &esk);
+    let m = "Hello World!".as_bytes();
+
+    let mut group = c.benchmark_group("falcon-512-shared");
+    group.throughput(Throughput::Elements(n as u64));
+
+    let threads = (0..).map(|i| 1 << i).take_while(|&t| t < cores);
+    for t in threads.chain([cores]) {
+        group.bench_with_input(BenchmarkId::new("sign_expanded", t), &t, |b, &t| {
+            b.iter(|| {
+                thread::scope(|scope| {
+                    // The first `n % t` threads take one message more, so every run signs `n`
+                    for i in 0..t {
+                        let count = n / t + usize::from(i < n % t);
+                        scope.spawn(move || {
+                            for _ in 0..count {
+                                shared.sign_expanded(esk, m).unwrap();
+                            }
+                        });
+                    }
+                })
+            })
+        });
+    }
+
+    group.finish();
 }
 
-criterion_group!(bench_all, bench_eddsa, bench_falcon);
+criterion_group!(
+    bench_all,
+    bench_eddsa,
+    bench_ecdsa,
+    bench_rsapss,
+    bench_falcon,
+    bench_falcon_expanded,
+    bench_falcon_shared,
+    bench_mldsa,
+    bench_slhdsa,
+    bench_composite,
+    bench_verify_batch
+);
 criterion_main!(bench_all);
@@ -1,36 +1,73 @@
+use std::env;
 use std::path::Path;
-use std::process::Command;
 
-const OBJS: [&str; 10] = [
-    "codec.o", "common.o", "falcon.o", "fft.o", "fpr.o", "keygen.o", "rng.o", "shake.o", "sign.o",
-    "vrfy.o",
+/// The library half of the Falcon reference implementation, vendored in `falcon/`; see
+/// `falcon/README.md` for where it comes from and what was changed.
+const SOURCES: [&str; 10] = [
+    "codec.c", "common.c", "falcon.c", "fft.c", "fpr.c", "keygen.c", "rng.c", "shake.c", "sign.c",
+    "vrfy.c",
 ];
 
 fn main() {
+    // The pure-Rust backend needs nothing built
+    if env::var_os("CARGO_FEATURE_FALCON_C").is_none() {
+        return;
+    }
+
     let falcon_path = Path::new("falcon");
+    let fpemu = env::var_os("CARGO_FEATURE_FALCON_FPEMU").is_some();
+    let avx2 = env::var_os("CARGO_FEATURE_FALCON_AVX2").is_some();
+
+    let arch = env::var("CARGO_CFG_TARGET_ARCH").unwrap();
+    let x86 = matches!(arch.as_str(), "x86" | "x86_64");
+
+    if fpemu && avx2 {
+        panic!("`falcon-fpemu` and `falcon-avx2` are exclusive: the AVX2 code needs the native FPU");
+    }
+    if avx2 && !x86 {
+        panic!("`falcon-avx2` needs an x86 or x86_64 target, not `{arch}`");
+    }
+
+    let mut build = cc::Build::new();
+
+    for src in SOURCES {
+        let path = falcon_path.join(src);
 
-    if !falcon_path.exists() {
-        panic!(
-            "Falcon path not found. Download Falcon and extract into a directory called `falcon`."
-        );
+        if !path.exists() {
+            panic!("Falcon source `{}` not found.", path.display());
+        }
+
+        println!("cargo::rerun-if-changed={}", path.display());
+        build.file(path);
     }
 
-    println!("cargo::rerun-if-changed={}", falcon_path.display());
+    println!("cargo::rerun-if-changed={}", falcon_path.join("config.h").display());
+    println!("cargo::rerun-if-changed={}", falcon_path.join("falcon.h").display());
+    println!("cargo::rerun-if-changed={}", falcon_path.join("inner.h").display());
 
-    Command::new("make")
-        .current_dir(falcon_path)
-        .status()
-        .unwrap_or_else(|e| panic!("Error building Falcon: {e}"));
+    // Endianness and unaligned access follow the target as `inner.h` would detect them; they
+    // only change speed. The C library's entropy source (`FALCON_RAND_*`) is still
+    // autodetected, and only used by `Falcon::new` without a seed.
+    let little_endian = env::var("CARGO_CFG_TARGET_ENDIAN").unwrap() == "little";
 
-    Command::new("ar")
-        .arg("rcs")
-        .arg("libfalcon.a")
-        .args(OBJS)
-        .current_dir(falcon_path)
-        .status()
-        .unwrap_or_else(|e| panic!("Error creating static library: {e}"));
+    // Every option that changes the arithmetic or the keys is set explicitly, so the same
+    // features give the same library on every machine
+    build
+        .include(falcon_path)
+        .opt_level(2)
+        .define("FALCON_FPEMU", if fpemu { "1" } else { "0" })
+        .define("FALCON_FPNATIVE", if fpemu { "0" } else { "1" })
+        .define("FALCON_AVX2", if avx2 { "1" } else { "0" })
+        .define("FALCON_FMA", if avx2 { "1" } else { "0" })
+        .define("FALCON_ASM_CORTEXM4", "0")
+        .define("FALCON_KG_CHACHA20", "0")
+        .define("FALCON_LE", if little_endian { "1" } else { "0" })
+        .define("FALCON_UNALIGNED", if x86 { "1" } else { "0" })
+        .warnings(false);
 
-    println!("cargo::rustc-link-search={}", falcon_path.display());
+    if avx2 {
+        build.flag_if_supported("-mavx2").flag_if_supported("-mfma");
+    }
 
-    println!("cargo::rustc-link-lib=falcon");
+    build.compile("falcon");
 }
@@ -0,0 +1,397 @@
+use rand_core::CryptoRngCore;
+use zeroize::Zeroizing;
+
+use crate::{
+    signing_scheme::{SchemeInfo, SigningScheme, ToBytes},
+    Error,
+};
+
+/// Prepended to every message before either half signs it, so that neither half's signature
+/// can be passed off as a standalone `A` or `B` signature over the same message.
+const DOMAIN: &[u8] = b"pqsign-composite-v1";
+
+/// Signs with both `A` and `B`; a signature verifies only if both halves do.
+///
+/// Both halves sign `DOMAIN || len(pk) || pk || m`, where `pk` is the encoded composite
+/// verifying key, which binds each half to the other half's key as well.
+pub struct Composite<A, B> {
+    a: A,
+    b: B,
+}
+
+impl<A: SigningScheme, B: SigningScheme> Composite<A, B> {
+    pub fn new(a: A, b: B) -> Self {
+        Self { a, b }
+    }
+}
+
+fn bind(pk: &[u8], m: &[u8]) -> Vec<u8> {
+    let mut res = DOMAIN.to_vec();
+    res.extend((pk.len() as u32).to_be_bytes());
+    res.extend(pk);
+    res.extend(m);
+    res
+}
+
+/// `len(a) || a || b`, with a 4-byte big-endian length.
+fn join(a: &[u8], b: &[u8]) -> Vec<u8> {
+    let mut res = (a.len() as u32).to_be_bytes().to_vec();
+    res.extend(a);
+    res.extend(b);
+    res
+}
+
+/// The inverse of `join`, with `base` added to error offsets.
+fn split_bytes<'a>(
+    value: &'a [u8],
+    field: &'static str,
+    base: usize,
+) -> Result<(&'a [u8], &'a [u8]), Error> {
+    let decode = |offset| Error::Decode {
+        field,
+        offset: base + offset,
+    };
+
+    let (len, rest) = value.split_at_checked(4).ok_or(decode(0))?;
+    let len = u32::from_be_bytes(len.try_into().unwrap()) as usize;
+    rest.split_at_checked(len).ok_or(decode(4))
+}
+
+fn split<'a, T, U>(value: &'a [u8], field: &'static str) -> Result<(T, U), Error>
+where
+    T: TryFrom<&'a [u8], Error = Error>,
+    U: TryFrom<&'a [u8], Error = Error>,
+{
+    let (a, b) = split_bytes(value, field, 0)?;
+    Ok((T::try_from(a)?, U::try_from(b)?))
+}
+
+pub struct SigningKey<A: SigningScheme, B: SigningScheme> {
+    a: A::SigningKey,
+    b: B::SigningKey,
+    /// The encoded composite verifying key, which every signature binds.
+    pk: Vec<u8>,
+}
+
+/// `len(a) || a || len(b) || b || pk`: the halves' signing keys, then the composite verifying
+/// key, which cannot be recomputed without instances of `A` and `B`.
+impl<A: SigningScheme, B: SigningScheme> ToBytes for SigningKey<A, B> {
+    fn to_bytes(&self) -> Vec<u8> {
+        let a = Zeroizing::new(self.a.to_bytes());
+        let b = Zeroizing::new(self.b.to_bytes());
+        join(&a, &Zeroizing::new(join(&b, &self.pk)))
+    }
+}
+
+impl<A: SigningScheme, B: SigningScheme> TryFrom<&[u8]> for SigningKey<A, B> {
+    type Error = Error;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        let field = "composite signing key";
+        let (a, rest) = split_bytes(value, field, 0)?;
+        let (b, pk) = split_bytes(rest, field, value.len() - rest.len())?;
+        VerifyingKey::<A, B>::try_from(pk)?;
+
+        Ok(SigningKey {
+            a: A::SigningKey::try_from(a)?,
+            b: B::SigningKey::try_from(b)?,
+            pk: pk.to_vec(),
+        })
+    }
+}
+
+pub struct VerifyingKey<A: SigningScheme, B: SigningScheme> {
+    a: A::VerifyingKey,
+    b: B::VerifyingKey,
+}
+
+impl<A: SigningScheme, B: SigningScheme> ToBytes for VerifyingKey<A, B> {
+    fn to_bytes(&self) -> Vec<u8> {
+        join(&self.a.to_bytes(), &self.b.to_bytes())
+    }
+}
+
+impl<A: SigningScheme, B: SigningScheme> TryFrom<&[u8]> for VerifyingKey<A, B> {
+    type Error = Error;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        let (a, b) = split(value, "composite verifying key")?;
+        Ok(VerifyingKey { a, b })
+    }
+}
+
+pub struct Signature<A: SigningScheme, B: SigningScheme> {
+    a: A::Signature,
+    b: B::Signature,
+}
+
+impl<A: SigningScheme, B: SigningScheme> ToBytes for Signature<A, B> {
+    fn to_bytes(&self) -> Vec<u8> {
+        join(&self.a.to_bytes(), &self.b.to_bytes())
+    }
+}
+
+impl<A: SigningScheme, B: SigningScheme> TryFrom<&[u8]> for Signature<A, B> {
+    type Error = Error;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        let (a, b) = split(value, "composite signature")?;
+        Ok(Signature { a, b })
+    }
+}
+
+fn keypair<A: SigningScheme, B: SigningScheme>(
+    sk_a: A::SigningKey,
+    pk_a: A::VerifyingKey,
+    sk_b: B::SigningKey,
+    pk_b: B::VerifyingKey,
+) -> (SigningKey<A, B>, VerifyingKey<A, B>) {
+    let pk = VerifyingKey { a: pk_a, b: pk_b };
+    let sk = SigningKey {
+        a: sk_a,
+        b: sk_b,
+        pk: pk.to_bytes(),
+    };
+    (sk, pk)
+}
+
+impl<A: SigningScheme, B: SigningScheme> SigningScheme for Composite<A, B> {
+    type SigningKey = SigningKey<A, B>;
+
+    type VerifyingKey = VerifyingKey<A, B>;
+
+    type Signature = Signature<A, B>;
+
+    /// As strong as the stronger half, since forging needs both.
+    fn info(&self) -> SchemeInfo {
+        let (a, b) = (self.a.info(), self.b.info());
+
+        let public_key_size = 4 + a.public_key_size + b.public_key_size;
+
+        SchemeInfo {
+            public_key_size,
+            secret_key_size: 8 + a.secret_key_size + b.secret_key_size + public_key_size,
+            max_signature_size: 4 + a.max_signature_size + b.max_signature_size,
+            typical_signature_size: 4 + a.typical_signature_size + b.typical_signature_size,
+            nist_level: a.nist_level.max(b.nist_level),
+        }
+    }
+
+    fn keygen(&mut self) -> Result<(Self::SigningKey, Self::VerifyingKey), Error> {
+        let (sk_a, pk_a) = self.a.keygen()?;
+        let (sk_b, pk_b) = self.b.keygen()?;
+        Ok(keypair::<A, B>(sk_a, pk_a, sk_b, pk_b))
+    }
+
+    fn keygen_with_rng(
+        &mut self,
+        rng: &mut dyn CryptoRngCore,
+    ) -> Result<(Self::SigningKey, Self::VerifyingKey), Error> {
+        let (sk_a, pk_a) = self.a.keygen_with_rng(rng)?;
+        let (sk_b, pk_b) = self.b.keygen_with_rng(rng)?;
+        Ok(keypair::<A, B>(sk_a, pk_a, sk_b, pk_b))
+    }
+
+    fn public_key(&self, sk: &Self::SigningKey) -> Result<Self::VerifyingKey, Error> {
+        Ok(VerifyingKey {
+            a: self.a.public_key(&sk.a)?,
+            b: self.b.public_key(&sk.b)?,
+        })
+    }
+
+    fn sign(&mut self, sk: &Self::SigningKey, m: &[u8]) -> Result<Self::Signature, Error> {
+        let m = bind(&sk.pk, m);
+
+        Ok(Signature {
+            a: self.a.sign(&sk.a, &m)?,
+            b: self.b.sign(&sk.b, &m)?,
+        })
+    }
+
+    fn verify(
+        &mut self,
+        pk: &Self::VerifyingKey,
+        m: &[u8],
+        t: &Self::Signature,
+    ) -> Result<(), Error> {
+        let m = bind(&pk.to_bytes(), m);
+
+        self.a.verify(&pk.a, &m, &t.a)?;
+        self.b.verify(&pk.b, &m, &t.b)
+    }
+
+    /// Batches each half separately, so both keep their specialised batch verification.
+    fn verify_batch(
+        &mut self,
+        items: &[(&Self::VerifyingKey, &[u8], &Self::Signature)],
+    ) -> Vec<Result<(), Error>> {
+        let messages: Vec<_> = items
+            .iter()
+            .map(|(pk, m, _)| bind(&pk.to_bytes(), m))
+            .collect();
+
+        let a: Vec<_> = items
+            .iter()
+            .zip(&messages)
+            .map(|((pk, _, t), m)| (&pk.a, &m[..], &t.a))
+            .collect();
+        let b: Vec<_> = items
+            .iter()
+            .zip(&messages)
+            .map(|((pk, _, t), m)| (&pk.b, &m[..], &t.b))
+            .collect();
+
+        self.a
+            .verify_batch(&a)
+            .into_iter()
+            .zip(self.b.verify_batch(&b))
+            .map(|(a, b)| a.and(b))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        eddsa::Eddsa,
+        falcon::{Degree, Falcon},
+    };
+
+    const M: &[u8] = b"composite round trip";
+
+    type Hybrid = Composite<Eddsa, Falcon>;
+
+    fn hybrid() -> Hybrid {
+        Composite::new(Eddsa::new(), Falcon::new(Degree::F512, Some(b"composite")))
+    }
+
+    fn verify_bytes(scheme: &mut Hybrid, pk: &[u8], t: &[u8]) -> Result<(), Error> {
+        let pk = VerifyingKey::try_from(pk)?;
+        let t = Signature::try_from(t)?;
+        scheme.verify(&pk, M, &t)
+    }
+
+    #[test]
+    fn round_trips_through_the_encoding() {
+        let mut scheme = hybrid();
+        let info = scheme.info();
+        let (sk, pk) = scheme.keygen().unwrap();
+        let t = scheme.sign(&sk, M).unwrap();
+
+        let (pk, t) = (pk.to_bytes(), t.to_bytes());
+        assert_eq!(pk.len(), info.public_key_size);
+        assert!(t.len() <= info.max_signature_size);
+        assert_eq!(scheme.public_key(&sk).unwrap().to_bytes(), pk);
+        assert_eq!(verify_bytes(&mut scheme, &pk, &t), Ok(()));
+
+        let decoded = VerifyingKey::<Eddsa, Falcon>::try_from(&pk[..]).unwrap();
+        assert_eq!(decoded.to_bytes(), pk);
+        assert_eq!(
+            Signature::<Eddsa, Falcon>::try_from(&t[..])
+                .unwrap()
+                .to_bytes(),
+            t
+        );
+    }
+
+    #[test]
+    fn tampering_with_either_half_fails() {
+        let mut scheme = hybrid();
+        let (sk, pk) = scheme.keygen().unwrap();
+        let (pk, t) = (pk.to_bytes(), scheme.sign(&sk, M).unwrap().to_bytes());
+
+        // Ed25519 signatures are 64 bytes, so the Falcon half starts at 4 + 64
+        for at in [4 + 10, 4 + 64 + 10, t.len() - 1] {
+            let mut mutant = t.clone();
+            mutant[at] ^= 1;
+            assert!(verify_bytes(&mut scheme, &pk, &mutant).is_err(), "{at}");
+        }
+
+        // A valid half with the other half from a different key
+        let (other_sk, other_pk) = scheme.keygen().unwrap();
+        let other = scheme.sign(&other_sk, M).unwrap();
+        let ours = scheme.sign(&sk, M).unwrap();
+        let pk = VerifyingKey::try_from(&pk[..]).unwrap();
+        for t in [
+            Signature {
+                a: ours.a,
+                b: other.b,
+            },
+            Signature {
+                a: other.a,
+                b: ours.b,
+            },
+        ] {
+            assert!(scheme.verify(&pk, M, &t).is_err());
+        }
+
+        // Swapping one half of the key breaks the binding even with matching signatures
+        let mixed = join(&pk.a.to_bytes(), &other_pk.b.to_bytes());
+        let mixed = VerifyingKey::try_from(&mixed[..]).unwrap();
+        let t = scheme.sign(&sk, M).unwrap();
+        assert!(scheme.verify(&mixed, M, &t).is_err());
+    }
+
+    /// Each half signs the bound message, so a plain signature over `m` does not pass as one.
+    #[test]
+    fn standalone_signatures_are_not_halves() {
+        let mut scheme = hybrid();
+        let (sk, pk) = scheme.keygen().unwrap();
+        let t = scheme.sign(&sk, M).unwrap();
+
+        let standalone = Signature {
+            a: scheme.a.sign(&sk.a, M).unwrap(),
+            b: t.b,
+        };
+        assert!(scheme.verify(&pk, M, &standalone).is_err());
+        assert!(scheme.a.verify(&pk.a, M, &t.a).is_err());
+    }
+
+    #[test]
+    fn batches_check_both_halves() {
+        let mut scheme = hybrid();
+        let (sk, pk) = scheme.keygen().unwrap();
+        let t = scheme.sign(&sk, M).unwrap();
+
+        let mut bytes = t.to_bytes();
+        bytes[4 + 10] ^= 1;
+        let bad_a = Signature::try_from(&bytes[..]).unwrap();
+        let mut bytes = t.to_bytes();
+        bytes[4 + 64 + 10] ^= 1;
+        let bad_b = Signature::try_from(&bytes[..]).unwrap();
+
+        let items = [(&pk, M, &t), (&pk, M, &bad_a), (&pk, M, &bad_b)];
+        let res: Vec<_> = scheme
+            .verify_batch(&items)
+            .iter()
+            .map(Result::is_ok)
+            .collect();
+        assert_eq!(res, [true, false, false]);
+    }
+
+    #[test]
+    fn malformed_encodings_are_rejected() {
+        let mut scheme = hybrid();
+        let (sk, pk) = scheme.keygen().unwrap();
+        let (pk, t) = (pk.to_bytes(), scheme.sign(&sk, M).unwrap().to_bytes());
+
+        let mut too_long = pk.clone();
+        too_long[..4].copy_from_slice(&u32::MAX.to_be_bytes());
+        assert_eq!(
+            VerifyingKey::<Eddsa, Falcon>::try_from(&too_long[..]).map(|_| ()),
+            Err(Error::Decode {
+                field: "composite verifying key",
+                offset: 4,
+            })
+        );
+        assert_eq!(
+            Signature::<Eddsa, Falcon>::try_from(&t[..3]).map(|_| ()),
+            Err(Error::Decode {
+                field: "composite signature",
+                offset: 0,
+            })
+        );
+        assert!(verify_bytes(&mut scheme, &pk, &t[..t.len() - 1]).is_err());
+    }
+}
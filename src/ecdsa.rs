@@ -0,0 +1,321 @@
+use std::{fmt, marker::PhantomData, ops::Add};
+
+use ::ecdsa::{
+    der::MaxOverhead,
+    elliptic_curve::{
+        generic_array::{typenum::Unsigned, ArrayLength},
+        ops::Invert,
+        sec1::{FromEncodedPoint, ModulusSize, ToEncodedPoint},
+        subtle::CtOption,
+        CurveArithmetic, PrimeCurve,
+    },
+    hazmat::{DigestPrimitive, SignPrimitive, VerifyPrimitive},
+    signature::{Signer, Verifier},
+};
+use rand::rngs::OsRng;
+use rand_core::CryptoRngCore;
+
+use crate::{
+    registry::AlgorithmId,
+    signing_scheme::{SchemeInfo, SigningScheme, ToBytes},
+    Error,
+};
+
+pub type EcdsaP256 = Ecdsa<p256::NistP256>;
+pub type EcdsaP384 = Ecdsa<p384::NistP384>;
+
+/// A curve ECDSA can be run over, with its standard hash (SHA-256 for P-256, SHA-384 for P-384).
+pub trait Curve: PrimeCurve<
+        FieldBytesSize: ModulusSize
+                            + Add<Output: ArrayLength<u8> + Add<MaxOverhead, Output: ArrayLength<u8>>>,
+    > + CurveArithmetic<
+        Scalar: Invert<Output = CtOption<Self::Scalar>> + SignPrimitive<Self>,
+        AffinePoint: FromEncodedPoint<Self> + ToEncodedPoint<Self> + VerifyPrimitive<Self>,
+    > + DigestPrimitive
+{
+    const ALGORITHM: AlgorithmId;
+}
+
+impl Curve for p256::NistP256 {
+    const ALGORITHM: AlgorithmId = AlgorithmId::EcdsaP256;
+}
+
+impl Curve for p384::NistP384 {
+    const ALGORITHM: AlgorithmId = AlgorithmId::EcdsaP384;
+}
+
+/// ECDSA with deterministic (RFC 6979) nonces, uncompressed SEC1 public keys and DER signatures,
+/// as they appear in Web PKI certificates.
+pub struct Ecdsa<C>(PhantomData<fn() -> C>);
+
+impl<C: Curve> Ecdsa<C> {
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<C: Curve> Default for Ecdsa<C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub struct SigningKey<C: Curve>(::ecdsa::SigningKey<C>);
+
+impl<C: Curve> fmt::Debug for SigningKey<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SigningKey").finish_non_exhaustive()
+    }
+}
+
+/// Encoded as the big-endian secret scalar, as wide as the field.
+impl<C: Curve> ToBytes for SigningKey<C> {
+    fn to_bytes(&self) -> Vec<u8> {
+        self.0.to_bytes().to_vec()
+    }
+}
+
+impl<C: Curve> TryFrom<&[u8]> for SigningKey<C> {
+    type Error = Error;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        // `from_slice` would left-pad short input, so the width is checked here
+        if value.len() != C::FieldBytesSize::USIZE {
+            return Err(Error::EcdsaFormat);
+        }
+
+        ::ecdsa::SigningKey::from_slice(value)
+            .map(SigningKey)
+            .map_err(|_| Error::EcdsaFormat)
+    }
+}
+
+pub struct VerifyingKey<C: Curve>(::ecdsa::VerifyingKey<C>);
+
+impl<C: Curve> ToBytes for VerifyingKey<C> {
+    fn to_bytes(&self) -> Vec<u8> {
+        self.0.to_encoded_point(false).as_bytes().to_vec()
+    }
+}
+
+impl<C: Curve> TryFrom<&[u8]> for VerifyingKey<C> {
+    type Error = Error;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        ::ecdsa::VerifyingKey::from_sec1_bytes(value)
+            .map(VerifyingKey)
+            .map_err(|_| Error::EcdsaFormat)
+    }
+}
+
+pub struct Signature<C: Curve>(::ecdsa::der::Signature<C>);
+
+impl<C: Curve> ToBytes for Signature<C> {
+    fn to_bytes(&self) -> Vec<u8> {
+        self.0.as_bytes().to_vec()
+    }
+}
+
+impl<C: Curve> TryFrom<&[u8]> for Signature<C> {
+    type Error = Error;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        ::ecdsa::der::Signature::from_bytes(value)
+            .map(Signature)
+            .map_err(|_| Error::EcdsaFormat)
+    }
+}
+
+impl<C: Curve> SigningScheme for Ecdsa<C> {
+    type SigningKey = SigningKey<C>;
+
+    type VerifyingKey = VerifyingKey<C>;
+
+    type Signature = Signature<C>;
+
+    /// A DER signature is a sequence of two integers of up to `n + 1` bytes each, and is usually
+    /// one byte short of that, as `r` and `s` each need a leading zero byte only half the time.
+    fn info(&self) -> SchemeInfo {
+        let n = C::FieldBytesSize::USIZE;
+
+        SchemeInfo {
+            public_key_size: 2 * n + 1,
+            secret_key_size: n,
+            max_signature_size: 2 * n + 8,
+            typical_signature_size: 2 * n + 7,
+            nist_level: None,
+        }
+    }
+
+    fn keygen(&mut self) -> Result<(Self::SigningKey, Self::VerifyingKey), Error> {
+        self.keygen_with_rng(&mut OsRng)
+    }
+
+    fn keygen_with_rng(
+        &mut self,
+        mut rng: &mut dyn CryptoRngCore,
+    ) -> Result<(Self::SigningKey, Self::VerifyingKey), Error> {
+        let sk = ::ecdsa::SigningKey::random(&mut rng);
+        let pk = *sk.verifying_key();
+        Ok((SigningKey(sk), VerifyingKey(pk)))
+    }
+
+    fn public_key(&self, sk: &Self::SigningKey) -> Result<Self::VerifyingKey, Error> {
+        Ok(VerifyingKey(*sk.0.verifying_key()))
+    }
+
+    fn sign(&mut self, sk: &Self::SigningKey, m: &[u8]) -> Result<Self::Signature, Error> {
+        let t: ::ecdsa::Signature<C> = sk.0.try_sign(m).map_err(|_| Error::EcdsaSign)?;
+        Ok(Signature(t.to_der()))
+    }
+
+    fn verify(
+        &mut self,
+        pk: &Self::VerifyingKey,
+        m: &[u8],
+        t: &Self::Signature,
+    ) -> Result<(), Error> {
+        pk.0.verify(m, &t.0).map_err(|_| Error::EcdsaVerify)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use p256::NistP256;
+    use p384::NistP384;
+    use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
+
+    use super::*;
+
+    /// Signs `m` under the secret scalar `x` and checks the signature's `r || s`.
+    fn check<C: Curve>(x: &str, m: &[u8], rs: &str) {
+        let sk =
+            SigningKey::<C>(::ecdsa::SigningKey::from_slice(&hex::decode(x).unwrap()).unwrap());
+        let mut ecdsa = Ecdsa::<C>::new();
+
+        let t = ecdsa.sign(&sk, m).unwrap();
+        let fixed = ::ecdsa::Signature::<C>::from_der(&t.to_bytes()).unwrap();
+        assert_eq!(hex::encode(fixed.to_bytes()), rs);
+
+        let pk = ecdsa.public_key(&sk).unwrap();
+        assert_eq!(ecdsa.verify(&pk, m, &t), Ok(()));
+    }
+
+    /// RFC 6979 A.2.5: P-256 with SHA-256.
+    #[test]
+    fn rfc6979_p256() {
+        let x = "c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721";
+        check::<NistP256>(
+            x,
+            b"sample",
+            "efd48b2aacb6a8fd1140dd9cd45e81d69d2c877b56aaf991c34d0ea84eaf3716\
+             f7cb1c942d657c41d436c7a1b6e29f65f3e900dbb9aff4064dc4ab2f843acda8",
+        );
+        check::<NistP256>(
+            x,
+            b"test",
+            "f1abb023518351cd71d881567b1ea663ed3efcf6c5132b354f28d3b0b7d38367\
+             019f4113742a2b14bd25926b49c649155f267e60d3814b4c0cc84250e46f0083",
+        );
+    }
+
+    /// RFC 6979 A.2.6: P-384 with SHA-384.
+    #[test]
+    fn rfc6979_p384() {
+        let x = "6b9d3dad2e1b8c1c05b19875b6659f4de23c3b667bf297ba\
+                 9aa47740787137d896d5724e4c70a825f872c9ea60d2edf5";
+        check::<NistP384>(
+            x,
+            b"sample",
+            "94edbb92a5ecb8aad4736e56c691916b3f88140666ce9fa73d64c4ea95ad133c\
+             81a648152e44acf96e36dd1e80fabe4699ef4aeb15f178cea1fe40db2603138f\
+             130e740a19624526203b6351d0a3a94fa329c145786e679e7b82c71a38628ac8",
+        );
+        check::<NistP384>(
+            x,
+            b"test",
+            "8203b63d3c853e8d77227fb377bcf7b7b772e97892a80f36ab775d509d7a5feb\
+             0542a7f0812998da8f1dd3ca3cf023dbddd0760448d42d8a43af45af836fce4d\
+             e8be06b485e9b61b827c2f13173923e06a739f040649a667bf3b828246baa5a5",
+        );
+    }
+
+    /// Decodes and verifies, as a caller holding only bytes would.
+    fn verify_bytes<C: Curve>(pk: &[u8], m: &[u8], t: &[u8]) -> Result<(), Error> {
+        let pk = VerifyingKey::<C>::try_from(pk)?;
+        let t = Signature::<C>::try_from(t)?;
+        Ecdsa::<C>::new().verify(&pk, m, &t)
+    }
+
+    fn round_trip<C: Curve>() {
+        let mut rng = ChaCha20Rng::seed_from_u64(0);
+        let mut ecdsa = Ecdsa::<C>::new();
+        let info = ecdsa.info();
+
+        for i in 0..16u8 {
+            let (sk, pk) = ecdsa.keygen_with_rng(&mut rng).unwrap();
+            let m = [i; 32];
+            let pk = pk.to_bytes();
+            let t = ecdsa.sign(&sk, &m).unwrap().to_bytes();
+
+            assert_eq!(pk.len(), info.public_key_size);
+            assert!(t.len() <= info.max_signature_size);
+            assert_eq!(verify_bytes::<C>(&pk, &m, &t), Ok(()));
+
+            // Signing is deterministic
+            assert_eq!(ecdsa.sign(&sk, &m).unwrap().to_bytes(), t);
+        }
+    }
+
+    #[test]
+    fn round_trips() {
+        round_trip::<NistP256>();
+        round_trip::<NistP384>();
+    }
+
+    fn rejection<C: Curve>() {
+        let mut ecdsa = Ecdsa::<C>::new();
+        let (sk, pk) = ecdsa
+            .keygen_with_rng(&mut ChaCha20Rng::seed_from_u64(1))
+            .unwrap();
+        let m = b"negative test message";
+        let pk = pk.to_bytes();
+        let t = ecdsa.sign(&sk, m).unwrap().to_bytes();
+
+        assert_eq!(
+            verify_bytes::<C>(&pk, b"another message", &t),
+            Err(Error::EcdsaVerify)
+        );
+
+        for bit in 0..8 * t.len() {
+            let mut mutant = t.clone();
+            mutant[bit / 8] ^= 1 << (bit % 8);
+            assert!(verify_bytes::<C>(&pk, m, &mutant).is_err());
+        }
+
+        for bit in 8..8 * pk.len() {
+            let mut mutant = pk.clone();
+            mutant[bit / 8] ^= 1 << (bit % 8);
+            assert!(verify_bytes::<C>(&mutant, m, &t).is_err());
+        }
+
+        assert_eq!(
+            verify_bytes::<C>(&pk, m, &t[..t.len() - 1]),
+            Err(Error::EcdsaFormat)
+        );
+        assert_eq!(
+            verify_bytes::<C>(&pk[..pk.len() - 1], m, &t),
+            Err(Error::EcdsaFormat)
+        );
+        assert_eq!(
+            verify_bytes::<C>(&vec![0; pk.len()], m, &t),
+            Err(Error::EcdsaFormat)
+        );
+    }
+
+    #[test]
+    fn bad_keys_and_signatures_are_rejected() {
+        rejection::<NistP256>();
+        rejection::<NistP384>();
+    }
+}
@@ -1,8 +1,187 @@
-use ed25519_dalek::SigningKey;
+use std::{
+    fmt,
+    io::{self, Write},
+};
 
-use crate::signing_scheme::{SigningScheme, ToBytes};
+use curve25519_dalek::{edwards::CompressedEdwardsY, EdwardsPoint, Scalar};
+use ed25519_dalek::{hazmat::ExpandedSecretKey, Digest, Sha512};
+use rand_core::CryptoRngCore;
+use zeroize::Zeroizing;
 
-pub struct Eddsa;
+use crate::{
+    signing_scheme::{SchemeInfo, SigningScheme, ToBytes},
+    Error,
+};
+
+/// Which RFC 8032 variant signs and verifies messages.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum Variant {
+    /// Plain Ed25519.
+    #[default]
+    Pure,
+    /// Ed25519ctx, with a context of 1 to 255 bytes that separates protocol roles.
+    Context(Vec<u8>),
+    /// Ed25519ph: the SHA-512 of the message is signed, with a context of at most 255 bytes.
+    Prehashed(Vec<u8>),
+}
+
+/// Ed25519, pure with lenient verification unless configured otherwise.
+#[derive(Clone, Debug, Default)]
+pub struct Eddsa {
+    variant: Variant,
+    strict: bool,
+}
+
+impl Eddsa {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_variant(self, variant: Variant) -> Self {
+        Self { variant, ..self }
+    }
+
+    /// With `strict`, verification follows `verify_strict`: it also rejects small-order public
+    /// keys and `R` values, so a signature cannot verify under more than one key.
+    pub fn with_strict(self, strict: bool) -> Self {
+        Self { strict, ..self }
+    }
+
+    pub fn variant(&self) -> &Variant {
+        &self.variant
+    }
+
+    /// The context streamed Ed25519ph signatures are made under.
+    fn prehash_context(&self) -> &[u8] {
+        match &self.variant {
+            Variant::Prehashed(ctx) => ctx,
+            _ => &[],
+        }
+    }
+}
+
+/// `dom2(0, ctx)`, the Ed25519ctx prefix to both hashes.
+fn dom2(ctx: &[u8]) -> Result<Vec<u8>, Error> {
+    if ctx.is_empty() || ctx.len() > 255 {
+        return Err(Error::Ed25519ContextLength);
+    }
+
+    let mut res = b"SigEd25519 no Ed25519 collisions".to_vec();
+    res.extend([0, ctx.len() as u8]);
+    res.extend(ctx);
+    Ok(res)
+}
+
+/// ed25519-dalek only implements the pure and prehashed variants, so Ed25519ctx is done here.
+fn sign_ctx(sk: &SigningKey, ctx: &[u8], m: &[u8]) -> Result<ed25519_dalek::Signature, Error> {
+    let dom = dom2(ctx)?;
+    let esk = ExpandedSecretKey::from(sk.as_bytes());
+    let pk = sk.0.verifying_key();
+
+    let r = Scalar::from_hash(
+        Sha512::new()
+            .chain_update(&dom)
+            .chain_update(esk.hash_prefix)
+            .chain_update(m),
+    );
+    let big_r = EdwardsPoint::mul_base(&r).compress();
+    let k = Scalar::from_hash(
+        Sha512::new()
+            .chain_update(&dom)
+            .chain_update(big_r.as_bytes())
+            .chain_update(pk.as_bytes())
+            .chain_update(m),
+    );
+    let s = r + k * esk.scalar;
+
+    Ok(ed25519_dalek::Signature::from_components(
+        big_r.to_bytes(),
+        s.to_bytes(),
+    ))
+}
+
+/// Cofactorless, like ed25519-dalek's own `verify` and `verify_strict`.
+fn verify_ctx(
+    pk: &ed25519_dalek::VerifyingKey,
+    ctx: &[u8],
+    m: &[u8],
+    t: &ed25519_dalek::Signature,
+    strict: bool,
+) -> Result<(), Error> {
+    let dom = dom2(ctx)?;
+
+    let a = CompressedEdwardsY(pk.to_bytes())
+        .decompress()
+        .ok_or(Error::Ed25519PointDecompression)?;
+    let s = Option::from(Scalar::from_canonical_bytes(*t.s_bytes()))
+        .ok_or(Error::Ed25519ScalarFormat)?;
+
+    if strict {
+        let big_r = CompressedEdwardsY(*t.r_bytes())
+            .decompress()
+            .ok_or(Error::Ed25519PointDecompression)?;
+
+        if a.is_small_order() || big_r.is_small_order() {
+            return Err(Error::Ed25519Verify);
+        }
+    }
+
+    let k = Scalar::from_hash(
+        Sha512::new()
+            .chain_update(&dom)
+            .chain_update(t.r_bytes())
+            .chain_update(pk.as_bytes())
+            .chain_update(m),
+    );
+    let big_r = EdwardsPoint::vartime_double_scalar_mul_basepoint(&k, &-a, &s).compress();
+
+    match big_r.as_bytes() == t.r_bytes() {
+        true => Ok(()),
+        false => Err(Error::Ed25519Verify),
+    }
+}
+
+/// Failing offset is where the input ran out, or where the trailing bytes begin.
+fn fixed_size<const N: usize>(value: &[u8], field: &'static str) -> Result<[u8; N], Error> {
+    value.try_into().map_err(|_| Error::Decode {
+        field,
+        offset: value.len().min(N),
+    })
+}
+
+/// The 32-byte secret seed, which RFC 8032 calls the private key.
+pub struct SigningKey(ed25519_dalek::SigningKey);
+
+impl SigningKey {
+    pub fn from_bytes(seed: &[u8; 32]) -> Self {
+        SigningKey(ed25519_dalek::SigningKey::from_bytes(seed))
+    }
+
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        self.0.as_bytes()
+    }
+}
+
+impl fmt::Debug for SigningKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SigningKey").finish_non_exhaustive()
+    }
+}
+
+impl ToBytes for SigningKey {
+    fn to_bytes(&self) -> Vec<u8> {
+        self.as_bytes().to_vec()
+    }
+}
+
+impl TryFrom<&[u8]> for SigningKey {
+    type Error = Error;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        let seed = Zeroizing::new(fixed_size(value, "Ed25519 signing key")?);
+        Ok(SigningKey::from_bytes(&seed))
+    }
+}
 
 pub struct VerifyingKey(ed25519_dalek::VerifyingKey);
 
@@ -12,9 +191,14 @@ impl ToBytes for VerifyingKey {
     }
 }
 
-impl From<Vec<u8>> for VerifyingKey {
-    fn from(value: Vec<u8>) -> Self {
-        VerifyingKey(ed25519_dalek::VerifyingKey::from_bytes(&value.try_into().unwrap()).unwrap())
+impl TryFrom<&[u8]> for VerifyingKey {
+    type Error = Error;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        let bytes = fixed_size(value, "Ed25519 verifying key")?;
+        Ok(VerifyingKey(ed25519_dalek::VerifyingKey::from_bytes(
+            &bytes,
+        )?))
     }
 }
 
@@ -26,31 +210,62 @@ impl ToBytes for Signature {
     }
 }
 
-impl From<Vec<u8>> for Signature {
-    fn from(value: Vec<u8>) -> Self {
-        Signature(ed25519_dalek::Signature::from_bytes(
-            &value.try_into().unwrap(),
-        ))
+impl TryFrom<&[u8]> for Signature {
+    type Error = Error;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        let bytes = fixed_size(value, "Ed25519 signature")?;
+        Ok(Signature(ed25519_dalek::Signature::from_bytes(&bytes)))
     }
 }
 
 impl SigningScheme for Eddsa {
-    type SigningKey = ed25519_dalek::SigningKey;
+    type SigningKey = SigningKey;
 
     type VerifyingKey = VerifyingKey;
 
     type Signature = Signature;
 
-    fn keygen(&mut self) -> (Self::SigningKey, Self::VerifyingKey) {
+    fn info(&self) -> SchemeInfo {
+        SchemeInfo {
+            public_key_size: 32,
+            secret_key_size: 32,
+            max_signature_size: 64,
+            typical_signature_size: 64,
+            nist_level: None,
+        }
+    }
+
+    fn keygen(&mut self) -> Result<(Self::SigningKey, Self::VerifyingKey), Error> {
         use rand::rngs::OsRng;
-        let sk = SigningKey::generate(&mut OsRng);
+        self.keygen_with_rng(&mut OsRng)
+    }
+
+    fn keygen_with_rng(
+        &mut self,
+        rng: &mut dyn CryptoRngCore,
+    ) -> Result<(Self::SigningKey, Self::VerifyingKey), Error> {
+        let sk = ed25519_dalek::SigningKey::generate(rng);
         let pk = sk.verifying_key();
-        (sk, VerifyingKey(pk))
+        Ok((SigningKey(sk), VerifyingKey(pk)))
     }
 
-    fn sign(&mut self, sk: &Self::SigningKey, m: &[u8]) -> Self::Signature {
+    fn public_key(&self, sk: &Self::SigningKey) -> Result<Self::VerifyingKey, Error> {
+        Ok(VerifyingKey(sk.0.verifying_key()))
+    }
+
+    fn sign(&mut self, sk: &Self::SigningKey, m: &[u8]) -> Result<Self::Signature, Error> {
         use ed25519_dalek::Signer;
-        Signature(sk.sign(m))
+
+        let t = match &self.variant {
+            Variant::Pure => sk.0.try_sign(m)?,
+            Variant::Context(ctx) => sign_ctx(sk, ctx, m)?,
+            Variant::Prehashed(ctx) => {
+                sk.0.sign_prehashed(Sha512::new().chain_update(m), Some(ctx))?
+            }
+        };
+
+        Ok(Signature(t))
     }
 
     fn verify(
@@ -58,8 +273,440 @@ impl SigningScheme for Eddsa {
         VerifyingKey(pk): &Self::VerifyingKey,
         m: &[u8],
         Signature(t): &Self::Signature,
-    ) -> bool {
+    ) -> Result<(), Error> {
         use ed25519_dalek::Verifier;
-        pk.verify(m, t).is_ok()
+
+        let prehash = || Sha512::new().chain_update(m);
+
+        match (&self.variant, self.strict) {
+            (Variant::Pure, false) => Ok(pk.verify(m, t)?),
+            (Variant::Pure, true) => Ok(pk.verify_strict(m, t)?),
+            (Variant::Context(ctx), strict) => verify_ctx(pk, ctx, m, t, strict),
+            (Variant::Prehashed(ctx), false) => Ok(pk.verify_prehashed(prehash(), Some(ctx), t)?),
+            (Variant::Prehashed(ctx), true) => {
+                Ok(pk.verify_prehashed_strict(prehash(), Some(ctx), t)?)
+            }
+        }
+    }
+
+    // No `verify_batch` fast path: ed25519-dalek's batch check decompresses `R` and multiplies
+    // by the cofactor, so it accepts non-canonical `R` encodings and torsion that `verify`
+    // rejects, and screening those out first costs more than the batch saves.
+}
+
+/// Ed25519ph signer: the message is hashed with SHA-512 as it is fed through `Write`.
+/// The signatures verify with `StreamVerifier`, or with an `Eddsa` set to `Variant::Prehashed`
+/// under the same context.
+pub struct StreamSigner<'a> {
+    sk: &'a SigningKey,
+    context: &'a [u8],
+    hash: Sha512,
+}
+
+impl Write for StreamSigner<'_> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.hash.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl StreamSigner<'_> {
+    pub fn finish(self) -> Result<Signature, Error> {
+        Ok(Signature(
+            self.sk.0.sign_prehashed(self.hash, Some(self.context))?,
+        ))
+    }
+}
+
+/// Ed25519ph verifier for signatures made by `StreamSigner`.
+pub struct StreamVerifier<'a> {
+    pk: &'a VerifyingKey,
+    t: &'a Signature,
+    context: &'a [u8],
+    strict: bool,
+    hash: Sha512,
+}
+
+impl Write for StreamVerifier<'_> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.hash.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl StreamVerifier<'_> {
+    pub fn finish(self) -> Result<(), Error> {
+        let (pk, context, t) = (&self.pk.0, Some(self.context), &self.t.0);
+
+        match self.strict {
+            false => Ok(pk.verify_prehashed(self.hash, context, t)?),
+            true => Ok(pk.verify_prehashed_strict(self.hash, context, t)?),
+        }
+    }
+}
+
+impl Eddsa {
+    /// Signs under the `Variant::Prehashed` context, or the empty one for other variants.
+    pub fn signer<'a>(&'a self, sk: &'a SigningKey) -> StreamSigner<'a> {
+        StreamSigner {
+            sk,
+            context: self.prehash_context(),
+            hash: Sha512::new(),
+        }
+    }
+
+    pub fn verifier<'a>(&'a self, pk: &'a VerifyingKey, t: &'a Signature) -> StreamVerifier<'a> {
+        StreamVerifier {
+            pk,
+            t,
+            context: self.prehash_context(),
+            strict: self.strict,
+            hash: Sha512::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kat;
+
+    /// `(secret key, public key, message, context, signature)` from RFC 8032 section 7.
+    type Vector = (
+        &'static str,
+        &'static str,
+        &'static str,
+        &'static str,
+        &'static str,
+    );
+
+    const PURE: [Vector; 3] = [
+        (
+            "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60",
+            "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a",
+            "",
+            "",
+            "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b",
+        ),
+        (
+            "4ccd089b28ff96da9db6c346ec114e0f5b8a319f35aba624da8cf6ed4fb8a6fb",
+            "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c",
+            "72",
+            "",
+            "92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da085ac1e43e15996e458f3613d0f11d8c387b2eaeb4302aeeb00d291612bb0c00",
+        ),
+        (
+            "c5aa8df43f9f837bedb7442f31dcb7b166d38535076f094b85ce3a2e0b4458f7",
+            "fc51cd8e6218a1a38da47ed00230f0580816ed13ba3303ac5deb911548908025",
+            "af82",
+            "",
+            "6291d657deec24024827e69c3abe01a30ce548a284743a445e3680d7db5ac3ac18ff9b538d16f290ae67f760984dc6594a7c15e9716ed28dc027beceea1ec40a",
+        ),
+    ];
+
+    const CONTEXT: Vector = (
+        "0305334e381af78f141cb666f6199f57bc3495335a256a95bd2a55bf546663f6",
+        "dfc9425e4f968f7f0c29f0259cf5f9aed6851c2bb4ad8bfb860cfee0ab248292",
+        "f726936d19c800494e3fdaff20b276a8",
+        "666f6f",
+        "55a4cc2f70a54e04288c5f4cd1e45a7bb520b36292911876cada7323198dd87a8b36950b95130022907a7fb7c4e9b2d5f6cca685a587b4b21f4b888e4e7edb0d",
+    );
+
+    const PREHASHED: Vector = (
+        "833fe62409237b9d62ec77587520911e9a759cec1d19755b7da901b96dca3d42",
+        "ec172b93ad5e563bf4932c70e1245034c35467ef2efd4d64ebf819683467e2bf",
+        "616263",
+        "",
+        "98a70222f0b8121aa9d30f813d683f809e462b469c7ff87639499bb94e6dae4131f85042463c2a355a2003d062adf5aaa10b8c61e636062aaad11c2a26083406",
+    );
+
+    /// Signs and verifies the vector, leniently and strictly.
+    fn check(variant: fn(Vec<u8>) -> Variant, (sk, pk, m, ctx, t): Vector) {
+        let sk = SigningKey::from_bytes(&hex::decode(sk).unwrap().try_into().unwrap());
+        let m = hex::decode(m).unwrap();
+        let variant = variant(hex::decode(ctx).unwrap());
+
+        for strict in [false, true] {
+            let mut eddsa = Eddsa::new()
+                .with_variant(variant.clone())
+                .with_strict(strict);
+
+            let pk_ = eddsa.public_key(&sk).unwrap();
+            assert_eq!(hex::encode(pk_.to_bytes()), pk);
+
+            let t_ = eddsa.sign(&sk, &m).unwrap();
+            assert_eq!(hex::encode(t_.to_bytes()), t);
+            assert_eq!(eddsa.verify(&pk_, &m, &t_), Ok(()));
+        }
+    }
+
+    #[test]
+    fn rfc8032_ed25519() {
+        for v in PURE {
+            check(|_| Variant::Pure, v);
+        }
+    }
+
+    #[test]
+    fn rfc8032_ed25519ctx() {
+        check(Variant::Context, CONTEXT);
+    }
+
+    #[test]
+    fn rfc8032_ed25519ph() {
+        check(Variant::Prehashed, PREHASHED);
+
+        // Streamed signing is Ed25519ph under the same context
+        let (sk, _, m, _, t) = PREHASHED;
+        let sk = SigningKey::from_bytes(&hex::decode(sk).unwrap().try_into().unwrap());
+        let eddsa = Eddsa::new().with_variant(Variant::Prehashed(vec![]));
+        let mut signer = eddsa.signer(&sk);
+        signer.write_all(&hex::decode(m).unwrap()).unwrap();
+        assert_eq!(hex::encode(signer.finish().unwrap().to_bytes()), t);
+    }
+
+    const VARIANTS: [fn() -> Variant; 3] = [
+        || Variant::Pure,
+        || Variant::Context(b"ctx".to_vec()),
+        || Variant::Prehashed(vec![]),
+    ];
+
+    /// The group order `L`, little-endian.
+    const ORDER: [u8; 32] = [
+        0xed, 0xd3, 0xf5, 0x5c, 0x1a, 0x63, 0x12, 0x58, 0xd6, 0x9c, 0xf7, 0xa2, 0xde, 0xf9, 0xde,
+        0x14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x10,
+    ];
+
+    /// The identity's canonical encoding, `y = 1`, and its non-canonical one, `y = p + 1`.
+    const IDENTITY: [u8; 32] = {
+        let mut res = [0; 32];
+        res[0] = 1;
+        res
+    };
+    const IDENTITY_NON_CANONICAL: [u8; 32] = {
+        let mut res = [0xff; 32];
+        res[0] = 0xee;
+        res[31] = 0x7f;
+        res
+    };
+
+    /// Decodes and verifies, as a caller holding only bytes would.
+    fn verify_bytes(eddsa: &mut Eddsa, pk: &[u8], m: &[u8], t: &[u8]) -> Result<(), Error> {
+        let pk = VerifyingKey::try_from(pk)?;
+        let t = Signature::try_from(t)?;
+        eddsa.verify(&pk, m, &t)
+    }
+
+    fn schemes() -> impl Iterator<Item = Eddsa> {
+        VARIANTS.into_iter().flat_map(|variant| {
+            [false, true].map(|strict| Eddsa::new().with_variant(variant()).with_strict(strict))
+        })
+    }
+
+    fn test_key() -> SigningKey {
+        SigningKey::from_bytes(&[7; 32])
+    }
+
+    #[test]
+    fn mutated_signatures_and_keys_are_rejected() {
+        let sk = test_key();
+        let m = b"negative test message";
+
+        for mut eddsa in schemes() {
+            let pk = eddsa.public_key(&sk).unwrap().to_bytes();
+            let t = eddsa.sign(&sk, m).unwrap().to_bytes();
+            assert_eq!(verify_bytes(&mut eddsa, &pk, m, &t), Ok(()));
+
+            for bit in 0..8 * t.len() {
+                let mut mutant = t.clone();
+                mutant[bit / 8] ^= 1 << (bit % 8);
+                assert!(verify_bytes(&mut eddsa, &pk, m, &mutant).is_err());
+            }
+
+            for bit in 0..8 * pk.len() {
+                let mut mutant = pk.clone();
+                mutant[bit / 8] ^= 1 << (bit % 8);
+                assert!(verify_bytes(&mut eddsa, &mutant, m, &t).is_err());
+            }
+
+            assert_eq!(
+                verify_bytes(&mut eddsa, &pk, m, &t[..63]),
+                Err(Error::Decode {
+                    field: "Ed25519 signature",
+                    offset: 63
+                })
+            );
+            assert_eq!(
+                verify_bytes(&mut eddsa, &pk, m, &[&t[..], &[0]].concat()),
+                Err(Error::Decode {
+                    field: "Ed25519 signature",
+                    offset: 64
+                })
+            );
+            assert_eq!(
+                verify_bytes(&mut eddsa, &pk[..31], m, &t),
+                Err(Error::Decode {
+                    field: "Ed25519 verifying key",
+                    offset: 31
+                })
+            );
+        }
+    }
+
+    /// `S + L` satisfies the verification equation as well as `S` does, so only the range check
+    /// stops a second signature being made from the first.
+    #[test]
+    fn non_canonical_scalars_are_rejected() {
+        let sk = test_key();
+        let m = b"malleability";
+
+        for mut eddsa in schemes() {
+            let pk = eddsa.public_key(&sk).unwrap().to_bytes();
+            let mut t = eddsa.sign(&sk, m).unwrap().to_bytes();
+
+            let mut carry = 0;
+            for (s, l) in t[32..].iter_mut().zip(ORDER) {
+                let sum = u16::from(*s) + u16::from(l) + carry;
+                *s = sum as u8;
+                carry = sum >> 8;
+            }
+
+            assert_eq!(
+                verify_bytes(&mut eddsa, &pk, m, &t),
+                Err(Error::Ed25519ScalarFormat)
+            );
+        }
+    }
+
+    /// With the identity as public key, `R = identity, S = 0` passes the cofactorless equation for
+    /// every message. Only strict verification refuses small-order keys.
+    #[test]
+    fn small_order_keys_are_rejected_when_strict() {
+        let t = [IDENTITY, [0; 32]].concat();
+
+        for mut eddsa in schemes() {
+            let expected = match eddsa.strict {
+                false => Ok(()),
+                true => Err(Error::Ed25519Verify),
+            };
+
+            for m in [&b""[..], b"any message"] {
+                assert_eq!(verify_bytes(&mut eddsa, &IDENTITY, m, &t), expected);
+            }
+        }
+    }
+
+    /// A non-canonical `R` never equals the recomputed one, and a non-canonical key encoding
+    /// of the identity is still small order.
+    #[test]
+    fn non_canonical_points_are_rejected() {
+        for mut eddsa in schemes() {
+            let t = [IDENTITY_NON_CANONICAL, [0; 32]].concat();
+            assert!(verify_bytes(&mut eddsa, &IDENTITY, b"m", &t).is_err());
+
+            if eddsa.strict {
+                let t = [IDENTITY, [0; 32]].concat();
+                assert!(verify_bytes(&mut eddsa, &IDENTITY_NON_CANONICAL, b"m", &t).is_err());
+            }
+        }
+
+        // y = 2 is not on the curve
+        let mut pk = [0; 32];
+        pk[0] = 2;
+        assert_eq!(
+            VerifyingKey::try_from(&pk[..]).map(|_| ()),
+            Err(Error::Ed25519PointDecompression)
+        );
+    }
+
+    /// A batch must reach the same verdict as `verify` on every item, including the
+    /// small-order and non-canonical cases above.
+    #[test]
+    fn batches_agree_with_verify() {
+        let sk = test_key();
+        let mut eddsa = Eddsa::new();
+        let pk = eddsa.public_key(&sk).unwrap();
+        let t = eddsa.sign(&sk, b"m").unwrap();
+
+        let identity = VerifyingKey::try_from(&IDENTITY[..]).unwrap();
+        let identity_non_canonical = VerifyingKey::try_from(&IDENTITY_NON_CANONICAL[..]).unwrap();
+        let small_order = Signature::try_from(&[IDENTITY, [0; 32]].concat()[..]).unwrap();
+        let r_non_canonical =
+            Signature::try_from(&[IDENTITY_NON_CANONICAL, [0; 32]].concat()[..]).unwrap();
+
+        let items = [
+            (&pk, &b"m"[..], &t),
+            (&identity, b"m", &small_order),
+            (&identity_non_canonical, b"m", &small_order),
+            (&identity, b"m", &r_non_canonical),
+        ];
+        for mut eddsa in schemes() {
+            let single: Vec<_> = items
+                .iter()
+                .map(|(pk, m, t)| eddsa.verify(pk, m, t))
+                .collect();
+            assert_eq!(eddsa.verify_batch(&items), single, "{eddsa:?}");
+        }
+    }
+
+    #[test]
+    fn batches_report_each_failure() {
+        let sk = test_key();
+        let mut eddsa = Eddsa::new();
+        let pk = eddsa.public_key(&sk).unwrap();
+        let t = eddsa.sign(&sk, b"m").unwrap();
+        let mut bytes = t.to_bytes();
+        bytes[40] ^= 1;
+        let mutant = Signature::try_from(&bytes[..]).unwrap();
+
+        let items = [(&pk, &b"m"[..], &t), (&pk, b"m", &mutant), (&pk, b"x", &t)];
+        for mut eddsa in schemes().filter(|e| e.variant == Variant::Pure) {
+            let res: Vec<_> = eddsa
+                .verify_batch(&items)
+                .iter()
+                .map(Result::is_ok)
+                .collect();
+            assert_eq!(res, [true, false, false]);
+        }
+    }
+
+    /// Wycheproof's `ed25519_test.json`. Valid signatures must pass both modes; invalid ones
+    /// must fail strict verification, and anything may fail but nothing may panic.
+    #[test]
+    fn wycheproof_ed25519() {
+        let mut count = 0;
+        for group in kat::acvp_groups("ed25519_test.json") {
+            let pk = kat::hex(&group["publicKey"]["pk"]);
+
+            for test in group["tests"].as_array().unwrap() {
+                let (id, m, t) = (
+                    &test["tcId"],
+                    kat::hex(&test["msg"]),
+                    kat::hex(&test["sig"]),
+                );
+                let lenient = verify_bytes(&mut Eddsa::new(), &pk, &m, &t);
+                let strict = verify_bytes(&mut Eddsa::new().with_strict(true), &pk, &m, &t);
+
+                match test["result"].as_str().unwrap() {
+                    "valid" => {
+                        assert_eq!(lenient, Ok(()), "tcId {id}");
+                        assert_eq!(strict, Ok(()), "tcId {id}");
+                    }
+                    "invalid" => assert!(strict.is_err(), "tcId {id}"),
+                    _ => {}
+                }
+                count += 1;
+            }
+        }
+
+        assert!(count > 0);
     }
 }
@@ -0,0 +1,235 @@
+use std::fmt;
+
+use crate::registry::AlgorithmId;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// `FALCON_ERR_RANDOM`: the RNG failed to produce usable randomness.
+    FalconRandom,
+    /// `FALCON_ERR_SIZE`: an output buffer was too small.
+    FalconSize,
+    /// `FALCON_ERR_FORMAT`: a key or signature was malformed.
+    FalconFormat,
+    /// `FALCON_ERR_BADSIG`: the signature did not verify.
+    FalconBadSig,
+    /// `FALCON_ERR_BADARG`: an argument was out of range.
+    FalconBadArg,
+    /// `FALCON_ERR_INTERNAL`: the C library hit an internal error.
+    FalconInternal,
+    /// The requested `logn` is outside 1..=10.
+    InvalidDegree(u32),
+    /// A key or signature of one Falcon degree was used with an instance of another.
+    DegreeMismatch { expected: u32, found: u32 },
+
+    /// A curve point (public key or signature `R`) could not be decompressed.
+    Ed25519PointDecompression,
+    /// A signature scalar `s` was not canonical.
+    Ed25519ScalarFormat,
+    /// A key or signature had the wrong number of bytes.
+    Ed25519BytesLength,
+    /// The verification equation was not satisfied.
+    Ed25519Verify,
+    /// Batch verification was given arrays of different lengths.
+    Ed25519ArrayLength,
+    /// An Ed25519ph context was longer than 255 bytes.
+    Ed25519PrehashedContextLength,
+    /// An Ed25519ctx context was empty or longer than 255 bytes.
+    Ed25519ContextLength,
+    /// A secret key did not match its public key.
+    Ed25519MismatchedKeypair,
+
+    /// A signature's hint or response vector was not canonically encoded.
+    MlDsaFormat,
+    /// An ML-DSA context string was longer than 255 bytes.
+    MlDsaContextLength,
+    /// The signature did not verify.
+    MlDsaVerify,
+
+    /// A key or signature had the wrong length for its parameter set.
+    SlhDsaFormat,
+    /// The signature did not verify.
+    SlhDsaVerify,
+
+    /// A signing key was not a scalar in range, a public key was not a valid SEC1 point, or a
+    /// signature was not valid DER.
+    EcdsaFormat,
+    /// Signing failed.
+    EcdsaSign,
+    /// The signature did not verify.
+    EcdsaVerify,
+
+    /// A key was not PKCS#1 DER of the expected modulus size, or a signature had the wrong
+    /// length.
+    RsaFormat,
+    /// No suitable primes were found.
+    RsaKeygen,
+    /// Signing failed.
+    RsaSign,
+    /// The signature did not verify.
+    RsaVerify,
+
+    /// The signer's state file could not be read or written.
+    StateIo(std::io::ErrorKind),
+    /// Another signer holds the lock on the state file.
+    StateLocked,
+    /// Every one-time key has been used.
+    StateExhausted,
+    /// An HSS key needs 1..=8 levels and a total height of at most 63.
+    InvalidHssLevels,
+    /// The signature did not verify.
+    LmsVerify,
+    /// The signature did not verify.
+    XmssVerify,
+
+    /// No scheme is registered under the requested algorithm identifier or name.
+    UnknownAlgorithm,
+    /// A signing key was passed to a `DynSigningScheme` of another algorithm.
+    KeyTypeMismatch,
+    /// Data declared to be signed with one algorithm was signed or checked with another.
+    AlgorithmMismatch {
+        expected: AlgorithmId,
+        found: AlgorithmId,
+    },
+
+    /// Encoded input was truncated or malformed at `offset` while reading `field`.
+    Decode { field: &'static str, offset: usize },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::FalconRandom => write!(f, "Falcon: random source failed"),
+            Error::FalconSize => write!(f, "Falcon: buffer too small"),
+            Error::FalconFormat => write!(f, "Falcon: malformed key or signature"),
+            Error::FalconBadSig => write!(f, "Falcon: invalid signature"),
+            Error::FalconBadArg => write!(f, "Falcon: invalid argument"),
+            Error::FalconInternal => write!(f, "Falcon: internal error"),
+            Error::InvalidDegree(logn) => write!(f, "Falcon: unsupported degree 2^{logn}"),
+            Error::DegreeMismatch { expected, found } => {
+                write!(f, "Falcon: expected degree 2^{expected}, got 2^{found}")
+            }
+            Error::Ed25519PointDecompression => write!(f, "Ed25519: cannot decompress point"),
+            Error::Ed25519ScalarFormat => write!(f, "Ed25519: non-canonical scalar"),
+            Error::Ed25519BytesLength => write!(f, "Ed25519: wrong byte length"),
+            Error::Ed25519Verify => write!(f, "Ed25519: invalid signature"),
+            Error::Ed25519ArrayLength => write!(f, "Ed25519: batch arrays differ in length"),
+            Error::Ed25519PrehashedContextLength => {
+                write!(f, "Ed25519: prehash context longer than 255 bytes")
+            }
+            Error::Ed25519ContextLength => write!(f, "Ed25519: context must be 1 to 255 bytes"),
+            Error::Ed25519MismatchedKeypair => write!(f, "Ed25519: mismatched key pair"),
+            Error::MlDsaFormat => write!(f, "ML-DSA: malformed signature"),
+            Error::MlDsaContextLength => write!(f, "ML-DSA: context longer than 255 bytes"),
+            Error::MlDsaVerify => write!(f, "ML-DSA: invalid signature"),
+            Error::SlhDsaFormat => write!(f, "SLH-DSA: malformed key or signature"),
+            Error::SlhDsaVerify => write!(f, "SLH-DSA: invalid signature"),
+            Error::EcdsaFormat => write!(f, "ECDSA: malformed key or signature"),
+            Error::EcdsaSign => write!(f, "ECDSA: signing failed"),
+            Error::EcdsaVerify => write!(f, "ECDSA: invalid signature"),
+            Error::RsaFormat => write!(f, "RSA-PSS: malformed key or signature"),
+            Error::RsaKeygen => write!(f, "RSA-PSS: key generation failed"),
+            Error::RsaSign => write!(f, "RSA-PSS: signing failed"),
+            Error::RsaVerify => write!(f, "RSA-PSS: invalid signature"),
+            Error::StateIo(kind) => write!(f, "stateful signer: state file I/O failed: {kind}"),
+            Error::StateLocked => write!(f, "stateful signer: state file is locked"),
+            Error::StateExhausted => write!(f, "stateful signer: no one-time keys left"),
+            Error::InvalidHssLevels => write!(f, "HSS: unsupported levels"),
+            Error::LmsVerify => write!(f, "LMS: invalid signature"),
+            Error::XmssVerify => write!(f, "XMSS: invalid signature"),
+            Error::UnknownAlgorithm => write!(f, "unknown signature algorithm"),
+            Error::KeyTypeMismatch => write!(f, "signing key belongs to another algorithm"),
+            Error::AlgorithmMismatch { expected, found } => {
+                write!(f, "expected a {expected} signature, got {found}")
+            }
+            Error::Decode { field, offset } => {
+                write!(f, "cannot decode {field} at byte offset {offset}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<std::io::Error> for Error {
+    fn from(value: std::io::Error) -> Self {
+        Error::StateIo(value.kind())
+    }
+}
+
+impl From<ed25519_dalek::SignatureError> for Error {
+    /// ed25519-dalek only exposes its error kind through the message of the
+    /// wrapped source, so that is what we match on. Anything unrecognised is
+    /// a failed verification; `tests::dalek_errors_keep_their_kind` pins the
+    /// mapping, so a dalek upgrade that rewords a message fails there.
+    fn from(value: ed25519_dalek::SignatureError) -> Self {
+        use std::error::Error as _;
+
+        let msg = value.source().map(|e| e.to_string()).unwrap_or_default();
+
+        if msg.starts_with("Cannot decompress") {
+            Error::Ed25519PointDecompression
+        } else if msg.starts_with("Cannot use scalar") {
+            Error::Ed25519ScalarFormat
+        } else if msg.contains("bytes in length") {
+            Error::Ed25519BytesLength
+        } else if msg.starts_with("Arrays must be the same length") {
+            Error::Ed25519ArrayLength
+        } else if msg.starts_with("An ed25519ph signature") {
+            Error::Ed25519PrehashedContextLength
+        } else if msg.starts_with("Mismatched Keypair") {
+            Error::Ed25519MismatchedKeypair
+        } else {
+            Error::Ed25519Verify
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ed25519_dalek::{
+        ed25519::signature::{Signer, Verifier},
+        Digest, Sha512, Signature, SigningKey, VerifyingKey,
+    };
+
+    use super::Error;
+
+    /// Each dalek error kind, produced through dalek's own API.
+    #[test]
+    fn dalek_errors_keep_their_kind() {
+        let sk = SigningKey::from_bytes(&[7; 32]);
+        let vk = sk.verifying_key();
+        let t = sk.sign(b"m");
+
+        // About half of all y-coordinates are not on the curve
+        let off_curve = (0..=u8::MAX)
+            .find_map(|b| VerifyingKey::from_bytes(&[b; 32]).err())
+            .unwrap();
+        assert_eq!(Error::from(off_curve), Error::Ed25519PointDecompression);
+
+        let mut high_s = t.to_bytes();
+        high_s[63] |= 0xE0;
+        let err = vk
+            .verify(b"m", &Signature::from_bytes(&high_s))
+            .unwrap_err();
+        assert_eq!(Error::from(err), Error::Ed25519ScalarFormat);
+
+        let err = VerifyingKey::try_from(&[0; 31][..]).unwrap_err();
+        assert_eq!(Error::from(err), Error::Ed25519BytesLength);
+
+        let err = vk.verify(b"another message", &t).unwrap_err();
+        assert_eq!(Error::from(err), Error::Ed25519Verify);
+
+        let err = ed25519_dalek::verify_batch(&[b"m"], &[], &[vk]).unwrap_err();
+        assert_eq!(Error::from(err), Error::Ed25519ArrayLength);
+
+        let err = sk
+            .sign_prehashed(Sha512::new(), Some(&[0; 256]))
+            .unwrap_err();
+        assert_eq!(Error::from(err), Error::Ed25519PrehashedContextLength);
+
+        let mut keypair = sk.to_keypair_bytes();
+        keypair[32..].copy_from_slice(SigningKey::from_bytes(&[8; 32]).verifying_key().as_bytes());
+        let err = SigningKey::from_keypair_bytes(&keypair).unwrap_err();
+        assert_eq!(Error::from(err), Error::Ed25519MismatchedKeypair);
+    }
+}
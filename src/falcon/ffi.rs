@@ -0,0 +1,449 @@
+//! Bindings to the Falcon reference implementation, which `build.rs` builds and links.
+
+use libc::{c_int, c_uint, size_t};
+use zeroize::{Zeroize, Zeroizing};
+
+use super::{Degree, SigFormat, Signature, SigningKey, VerifyingKey, NONCE_LEN};
+use crate::Error;
+
+#[repr(C)]
+pub(super) struct shake256_context {
+    opaque_contents: [u64; 26],
+}
+
+/// The SHAKE256-based PRNG the library signs with.
+pub(super) type Rng = shake256_context;
+
+/// SHAKE256 over `nonce || message`, fed incrementally.
+pub(super) type Hasher = shake256_context;
+
+impl shake256_context {
+    fn empty() -> Self {
+        shake256_context {
+            opaque_contents: [0; 26],
+        }
+    }
+
+    pub(super) fn from_seed(seed: &[u8]) -> Self {
+        let mut sc = Self::empty();
+        unsafe { shake256_init_prng_from_seed(&mut sc, seed.as_ptr(), seed.len()) };
+        sc
+    }
+
+    pub(super) fn from_system() -> Self {
+        let mut sc = Self::empty();
+
+        unsafe {
+            let status = shake256_init_prng_from_system(&mut sc);
+            if status != 0 {
+                shake256_init_prng_from_seed(&mut sc, [].as_ptr(), 0);
+            }
+        }
+
+        sc
+    }
+
+    pub(super) fn inject(&mut self, data: &[u8]) {
+        unsafe { shake256_inject(self, data.as_ptr(), data.len()) };
+    }
+
+    pub(super) fn fill(&mut self, buf: &mut [u8]) {
+        unsafe { shake256_extract(self, buf.as_mut_ptr(), buf.len()) };
+    }
+}
+
+impl Drop for shake256_context {
+    fn drop(&mut self) {
+        self.opaque_contents.zeroize();
+    }
+}
+
+extern "C" {
+    fn shake256_init_prng_from_seed(sc: *mut shake256_context, seed: *const u8, seed_len: size_t);
+
+    fn shake256_init_prng_from_system(sc: *mut shake256_context) -> c_int;
+
+    fn shake256_inject(sc: *mut shake256_context, data: *const u8, len: size_t);
+
+    fn shake256_extract(sc: *mut shake256_context, out: *mut u8, len: size_t);
+
+    fn falcon_keygen_make(
+        rng: *mut shake256_context,
+        logn: c_uint,
+        privkey: *mut u8,
+        privkey_len: size_t,
+        pubkey: *mut u8,
+        pubkey_len: size_t,
+        tmp: *mut u8,
+        tmp_len: size_t,
+    ) -> c_int;
+
+    fn falcon_make_public(
+        pubkey: *mut u8,
+        pubkey_len: size_t,
+        privkey: *const u8,
+        privkey_len: size_t,
+        tmp: *mut u8,
+        tmp_len: size_t,
+    ) -> c_int;
+
+    fn falcon_sign_dyn(
+        rng: *mut shake256_context,
+        sig: *mut u8,
+        sig_len: *mut size_t,
+        sig_type: c_int,
+        privkey: *const u8,
+        privkey_len: size_t,
+        data: *const u8,
+        data_len: size_t,
+        tmp: *mut u8,
+        tmp_len: size_t,
+    ) -> c_int;
+
+    fn falcon_sign_start(
+        rng: *mut shake256_context,
+        nonce: *mut u8,
+        hash_data: *mut shake256_context,
+    ) -> c_int;
+
+    fn falcon_sign_dyn_finish(
+        rng: *mut shake256_context,
+        sig: *mut u8,
+        sig_len: *mut size_t,
+        sig_type: c_int,
+        privkey: *const u8,
+        privkey_len: size_t,
+        hash_data: *mut shake256_context,
+        nonce: *const u8,
+        tmp: *mut u8,
+        tmp_len: size_t,
+    ) -> c_int;
+
+    fn falcon_expand_privkey(
+        expanded_key: *mut u8,
+        expanded_key_len: size_t,
+        privkey: *const u8,
+        privkey_len: size_t,
+        tmp: *mut u8,
+        tmp_len: size_t,
+    ) -> c_int;
+
+    fn falcon_sign_tree(
+        rng: *mut shake256_context,
+        sig: *mut u8,
+        sig_len: *mut size_t,
+        sig_type: c_int,
+        expanded_key: *const u8,
+        data: *const u8,
+        data_len: size_t,
+        tmp: *mut u8,
+        tmp_len: size_t,
+    ) -> c_int;
+
+    fn falcon_verify(
+        sig: *const u8,
+        sig_len: size_t,
+        sig_type: c_int,
+        pubkey: *const u8,
+        pubkey_len: size_t,
+        data: *const u8,
+        data_len: size_t,
+        tmp: *mut u8,
+        tmp_len: size_t,
+    ) -> c_int;
+
+    fn falcon_verify_start(
+        hash_data: *mut shake256_context,
+        sig: *const u8,
+        sig_len: size_t,
+    ) -> c_int;
+
+    fn falcon_verify_finish(
+        sig: *const u8,
+        sig_len: size_t,
+        sig_type: c_int,
+        pubkey: *const u8,
+        pubkey_len: size_t,
+        hash_data: *mut shake256_context,
+        tmp: *mut u8,
+        tmp_len: size_t,
+    ) -> c_int;
+}
+
+/// Maps a `FALCON_ERR_*` return code.
+fn check(status: c_int) -> Result<(), Error> {
+    match status {
+        0 => Ok(()),
+        -1 => Err(Error::FalconRandom),
+        -2 => Err(Error::FalconSize),
+        -3 => Err(Error::FalconFormat),
+        -4 => Err(Error::FalconBadSig),
+        -5 => Err(Error::FalconBadArg),
+        _ => Err(Error::FalconInternal),
+    }
+}
+
+fn tmpsize_keygen(deg: Degree) -> usize {
+    let x = deg.logn() as usize;
+    (if x <= 3 { 272usize } else { 28usize << x } + (3usize << x) + 7)
+}
+
+fn tmpsize_makepub(deg: Degree) -> usize {
+    (6usize << deg.logn()) + 1
+}
+
+fn tmpsize_sign(deg: Degree) -> usize {
+    (78usize << deg.logn()) + 7
+}
+
+fn tmpsize_verify(deg: Degree) -> usize {
+    (8usize << deg.logn()) + 1
+}
+
+fn expanded_key_size(deg: Degree) -> usize {
+    let x = deg.logn() as usize;
+    ((8 * x + 40) << x) + 8
+}
+
+fn tmpsize_expand(deg: Degree) -> usize {
+    (52usize << deg.logn()) + 7
+}
+
+fn tmpsize_sign_tree(deg: Degree) -> usize {
+    (50usize << deg.logn()) + 7
+}
+
+/// A private key with its LDL tree precomputed.
+///
+/// The library aligns the key relative to the buffer address, so the bytes must stay in the
+/// buffer `falcon_expand_privkey` wrote them to; this is why the type is not `Clone`.
+pub(super) struct ExpandedKey(Vec<u8>);
+
+impl Drop for ExpandedKey {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+pub(super) fn keygen(deg: Degree, rng: &mut Rng) -> Result<(SigningKey, VerifyingKey), Error> {
+    // Built up front so the key is wiped even if generation fails
+    let mut sk = SigningKey {
+        deg,
+        bytes: vec![0; deg.sk_size()],
+    };
+    let mut pk: Vec<u8> = vec![0; deg.pk_size()];
+    let mut tmp = Zeroizing::new(vec![0u8; tmpsize_keygen(deg)]);
+
+    check(unsafe {
+        falcon_keygen_make(
+            rng,
+            deg.logn(),
+            sk.bytes.as_mut_ptr(),
+            sk.bytes.len(),
+            pk.as_mut_ptr(),
+            pk.len(),
+            tmp.as_mut_ptr(),
+            tmp.len(),
+        )
+    })?;
+
+    Ok((sk, VerifyingKey { deg, bytes: pk }))
+}
+
+pub(super) fn make_public(sk: &SigningKey) -> Result<VerifyingKey, Error> {
+    let mut pk: Vec<u8> = vec![0; sk.deg.pk_size()];
+    let mut tmp = Zeroizing::new(vec![0u8; tmpsize_makepub(sk.deg)]);
+
+    check(unsafe {
+        falcon_make_public(
+            pk.as_mut_ptr(),
+            pk.len(),
+            sk.bytes.as_ptr(),
+            sk.bytes.len(),
+            tmp.as_mut_ptr(),
+            tmp.len(),
+        )
+    })?;
+
+    Ok(VerifyingKey {
+        deg: sk.deg,
+        bytes: pk,
+    })
+}
+
+pub(super) fn sign(
+    rng: &mut Rng,
+    format: SigFormat,
+    sk: &SigningKey,
+    m: &[u8],
+) -> Result<Signature, Error> {
+    let mut t: Vec<u8> = vec![0; sk.deg.sig_size(format)];
+    let mut t_size: size_t = t.len();
+    let mut tmp = Zeroizing::new(vec![0u8; tmpsize_sign(sk.deg)]);
+
+    check(unsafe {
+        falcon_sign_dyn(
+            rng,
+            t.as_mut_ptr(),
+            &mut t_size,
+            format as c_int,
+            sk.bytes.as_ptr(),
+            sk.bytes.len(),
+            m.as_ptr(),
+            m.len(),
+            tmp.as_mut_ptr(),
+            tmp.len(),
+        )
+    })?;
+
+    t.resize(t_size, 0);
+
+    Ok(Signature {
+        deg: sk.deg,
+        bytes: t,
+    })
+}
+
+pub(super) fn sign_start(rng: &mut Rng) -> Result<([u8; NONCE_LEN], Hasher), Error> {
+    let mut nonce = [0; NONCE_LEN];
+    let mut hash = Hasher::empty();
+
+    check(unsafe { falcon_sign_start(rng, nonce.as_mut_ptr(), &mut hash) })?;
+
+    Ok((nonce, hash))
+}
+
+pub(super) fn sign_finish(
+    rng: &mut Rng,
+    format: SigFormat,
+    sk: &SigningKey,
+    mut hash: Hasher,
+    nonce: &[u8; NONCE_LEN],
+) -> Result<Signature, Error> {
+    let mut t: Vec<u8> = vec![0; sk.deg.sig_size(format)];
+    let mut t_size: size_t = t.len();
+    let mut tmp = Zeroizing::new(vec![0u8; tmpsize_sign(sk.deg)]);
+
+    check(unsafe {
+        falcon_sign_dyn_finish(
+            rng,
+            t.as_mut_ptr(),
+            &mut t_size,
+            format as c_int,
+            sk.bytes.as_ptr(),
+            sk.bytes.len(),
+            &mut hash,
+            nonce.as_ptr(),
+            tmp.as_mut_ptr(),
+            tmp.len(),
+        )
+    })?;
+
+    t.resize(t_size, 0);
+
+    Ok(Signature {
+        deg: sk.deg,
+        bytes: t,
+    })
+}
+
+pub(super) fn expand(sk: &SigningKey) -> Result<ExpandedKey, Error> {
+    let mut key = ExpandedKey(vec![0; expanded_key_size(sk.deg)]);
+    let mut tmp = Zeroizing::new(vec![0u8; tmpsize_expand(sk.deg)]);
+
+    check(unsafe {
+        falcon_expand_privkey(
+            key.0.as_mut_ptr(),
+            key.0.len(),
+            sk.bytes.as_ptr(),
+            sk.bytes.len(),
+            tmp.as_mut_ptr(),
+            tmp.len(),
+        )
+    })?;
+
+    Ok(key)
+}
+
+pub(super) fn sign_expanded(
+    rng: &mut Rng,
+    format: SigFormat,
+    deg: Degree,
+    key: &ExpandedKey,
+    m: &[u8],
+) -> Result<Signature, Error> {
+    let mut t: Vec<u8> = vec![0; deg.sig_size(format)];
+    let mut t_size: size_t = t.len();
+    let mut tmp = Zeroizing::new(vec![0u8; tmpsize_sign_tree(deg)]);
+
+    check(unsafe {
+        falcon_sign_tree(
+            rng,
+            t.as_mut_ptr(),
+            &mut t_size,
+            format as c_int,
+            key.0.as_ptr(),
+            m.as_ptr(),
+            m.len(),
+            tmp.as_mut_ptr(),
+            tmp.len(),
+        )
+    })?;
+
+    t.resize(t_size, 0);
+
+    Ok(Signature { deg, bytes: t })
+}
+
+pub(super) fn verify(
+    format: SigFormat,
+    pk: &VerifyingKey,
+    m: &[u8],
+    t: &Signature,
+) -> Result<(), Error> {
+    let mut tmp: Vec<u8> = vec![0; tmpsize_verify(pk.deg)];
+
+    check(unsafe {
+        falcon_verify(
+            t.bytes.as_ptr(),
+            t.bytes.len(),
+            format as c_int,
+            pk.bytes.as_ptr(),
+            pk.bytes.len(),
+            m.as_ptr(),
+            m.len(),
+            tmp.as_mut_ptr(),
+            tmp.len(),
+        )
+    })
+}
+
+pub(super) fn verify_start(t: &Signature) -> Result<Hasher, Error> {
+    let mut hash = Hasher::empty();
+
+    check(unsafe { falcon_verify_start(&mut hash, t.bytes.as_ptr(), t.bytes.len()) })?;
+
+    Ok(hash)
+}
+
+pub(super) fn verify_finish(
+    format: SigFormat,
+    pk: &VerifyingKey,
+    t: &Signature,
+    mut hash: Hasher,
+) -> Result<(), Error> {
+    let mut tmp: Vec<u8> = vec![0; tmpsize_verify(pk.deg)];
+
+    check(unsafe {
+        falcon_verify_finish(
+            t.bytes.as_ptr(),
+            t.bytes.len(),
+            format as c_int,
+            pk.bytes.as_ptr(),
+            pk.bytes.len(),
+            &mut hash,
+            tmp.as_mut_ptr(),
+            tmp.len(),
+        )
+    })
+}
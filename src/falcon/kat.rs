@@ -0,0 +1,304 @@
+//! Known-answer tests against the first entries of the NIST round-3 submission's
+//! `falcon512-KAT.rsp` and `falcon1024-KAT.rsp`, kept in `kat/`.
+//!
+//! Each entry's seed goes into the AES-256 CTR_DRBG the submission draws all its randomness
+//! from: 48 bytes seed the keygen PRNG, then 40 make the nonce and 48 more seed the signing PRNG.
+//! The C backend must reproduce keys and signatures byte for byte. The Rust backend samples
+//! differently, so with it the vectors are only checked to verify and to round-trip.
+
+use super::{backend, Degree, Falcon, SigFormat, Signature, SigningKey, VerifyingKey, NONCE_LEN};
+use crate::signing_scheme::SigningScheme;
+
+fn xtime(b: u8) -> u8 {
+    (b << 1) ^ if b & 0x80 != 0 { 0x1B } else { 0 }
+}
+
+/// The S-box, from walking the multiplicative group by powers of 3 and applying the affine map
+/// to each inverse.
+fn sbox() -> [u8; 256] {
+    let mut s = [0x63; 256];
+    let (mut p, mut q) = (1u8, 1u8);
+
+    loop {
+        p ^= xtime(p);
+        q ^= q << 1;
+        q ^= q << 2;
+        q ^= q << 4;
+        if q & 0x80 != 0 {
+            q ^= 0x09;
+        }
+
+        s[p as usize] =
+            q ^ q.rotate_left(1) ^ q.rotate_left(2) ^ q.rotate_left(3) ^ q.rotate_left(4) ^ 0x63;
+
+        if p == 1 {
+            return s;
+        }
+    }
+}
+
+/// AES-256 encryption only, which is all the DRBG uses.
+struct Aes256 {
+    sbox: [u8; 256],
+    round_keys: [[u8; 16]; 15],
+}
+
+impl Aes256 {
+    fn new(key: &[u8; 32]) -> Self {
+        let sbox = sbox();
+        let mut w = [[0u8; 4]; 60];
+        let mut rcon = 1;
+
+        for (i, word) in key.chunks(4).enumerate() {
+            w[i].copy_from_slice(word);
+        }
+
+        for i in 8..60 {
+            let mut t = w[i - 1];
+            if i % 8 == 0 {
+                t = [t[1], t[2], t[3], t[0]].map(|b| sbox[b as usize]);
+                t[0] ^= rcon;
+                rcon = xtime(rcon);
+            } else if i % 8 == 4 {
+                t = t.map(|b| sbox[b as usize]);
+            }
+
+            for j in 0..4 {
+                w[i][j] = w[i - 8][j] ^ t[j];
+            }
+        }
+
+        let mut round_keys = [[0u8; 16]; 15];
+        for (key, words) in round_keys.iter_mut().zip(w.chunks(4)) {
+            key.copy_from_slice(words.as_flattened());
+        }
+
+        Aes256 { sbox, round_keys }
+    }
+
+    fn encrypt(&self, block: &[u8; 16]) -> [u8; 16] {
+        let add_key =
+            |s: &mut [u8; 16], key: &[u8; 16]| s.iter_mut().zip(key).for_each(|(a, b)| *a ^= b);
+        let mut s = *block;
+        add_key(&mut s, &self.round_keys[0]);
+
+        for (round, key) in self.round_keys.iter().enumerate().skip(1) {
+            // SubBytes and ShiftRows; byte `r + 4c` is row `r` of column `c`
+            let t = s;
+            for (i, b) in s.iter_mut().enumerate() {
+                let (r, c) = (i % 4, i / 4);
+                *b = self.sbox[t[r + 4 * ((c + r) % 4)] as usize];
+            }
+
+            if round != 14 {
+                for col in s.chunks_mut(4) {
+                    let a = [col[0], col[1], col[2], col[3]];
+                    let all = a[0] ^ a[1] ^ a[2] ^ a[3];
+                    for r in 0..4 {
+                        col[r] = a[r] ^ all ^ xtime(a[r] ^ a[(r + 1) % 4]);
+                    }
+                }
+            }
+
+            add_key(&mut s, key);
+        }
+
+        s
+    }
+}
+
+/// The NIST `randombytes` AES-256 CTR_DRBG, without personalisation or reseeding.
+struct CtrDrbg {
+    key: [u8; 32],
+    v: [u8; 16],
+}
+
+impl CtrDrbg {
+    fn new(seed: &[u8]) -> Self {
+        let mut drbg = CtrDrbg {
+            key: [0; 32],
+            v: [0; 16],
+        };
+        drbg.update(Some(seed));
+        drbg
+    }
+
+    fn next_block(&mut self, aes: &Aes256) -> [u8; 16] {
+        for b in self.v.iter_mut().rev() {
+            *b = b.wrapping_add(1);
+            if *b != 0 {
+                break;
+            }
+        }
+
+        aes.encrypt(&self.v)
+    }
+
+    fn update(&mut self, data: Option<&[u8]>) {
+        let aes = Aes256::new(&self.key);
+        let mut temp = [0u8; 48];
+        for chunk in temp.chunks_mut(16) {
+            chunk.copy_from_slice(&self.next_block(&aes));
+        }
+
+        if let Some(data) = data {
+            temp.iter_mut().zip(data).for_each(|(a, b)| *a ^= b);
+        }
+
+        self.key.copy_from_slice(&temp[..32]);
+        self.v.copy_from_slice(&temp[32..]);
+    }
+
+    fn bytes(&mut self, len: usize) -> Vec<u8> {
+        let aes = Aes256::new(&self.key);
+        let mut res = Vec::with_capacity(len + 16);
+        while res.len() < len {
+            res.extend(self.next_block(&aes));
+        }
+        res.truncate(len);
+
+        self.update(None);
+        res
+    }
+}
+
+struct Entry {
+    count: usize,
+    #[cfg_attr(not(feature = "falcon-c"), allow(dead_code))]
+    seed: Vec<u8>,
+    msg: Vec<u8>,
+    pk: Vec<u8>,
+    sk: Vec<u8>,
+    sm: Vec<u8>,
+}
+
+/// The entries of a `.rsp` file in `kat/`.
+fn read_rsp(name: &str) -> Vec<Entry> {
+    crate::kat::rsp(name)
+        .iter()
+        .map(|fields| {
+            let hex = |key: &str| hex::decode(&fields[key]).unwrap();
+            Entry {
+                count: fields["count"].parse().unwrap(),
+                seed: hex("seed"),
+                msg: hex("msg"),
+                pk: hex("pk"),
+                sk: hex("sk"),
+                sm: hex("sm"),
+            }
+        })
+        .collect()
+}
+
+/// The submission's signed message is `len || nonce || m || (0x20 + logn) || s2`, with `len`
+/// counting the last two parts; as an API signature that is `(0x30 + logn) || nonce || s2`.
+fn detached_signature(deg: Degree, e: &Entry) -> Signature {
+    let (len, rest) = e.sm.split_at(2);
+    let (nonce, rest) = rest.split_at(NONCE_LEN);
+    let (m, esig) = rest.split_at(e.msg.len());
+
+    assert_eq!(m, e.msg, "count {}", e.count);
+    assert_eq!(
+        usize::from(u16::from_be_bytes([len[0], len[1]])),
+        esig.len()
+    );
+    assert_eq!(esig[0], 0x20 + deg.logn() as u8);
+
+    let mut t = vec![0x30 + deg.logn() as u8];
+    t.extend(nonce);
+    t.extend(&esig[1..]);
+    Signature::try_from(&t[..]).unwrap()
+}
+
+fn run_kat(deg: Degree, name: &str) {
+    let entries = read_rsp(name);
+    assert!(!entries.is_empty());
+
+    // The submission's signatures are compressed, so they should average out near the mean
+    let total: usize = entries
+        .iter()
+        .map(|e| detached_signature(deg, e).bytes.len())
+        .sum();
+    let typical = Falcon::new(deg, Some(b"kat")).info().typical_signature_size;
+    assert!(total.abs_diff(typical * entries.len()) <= 5 * entries.len());
+
+    for e in &entries {
+        let pk = VerifyingKey::try_from(&e.pk[..]).unwrap();
+        let sk = SigningKey::try_from(&e.sk[..]).unwrap();
+        let t = detached_signature(deg, e);
+
+        assert_eq!(
+            backend::make_public(&sk),
+            Ok(pk.clone()),
+            "count {}",
+            e.count
+        );
+        assert_eq!(
+            backend::verify(SigFormat::Compressed, &pk, &e.msg, &t),
+            Ok(()),
+            "count {}",
+            e.count
+        );
+
+        #[cfg(feature = "falcon-c")]
+        reproduce_with_c(deg, e, &t);
+    }
+}
+
+#[cfg(feature = "falcon-c")]
+fn reproduce_with_c(deg: Degree, e: &Entry, t: &Signature) {
+    use super::{ffi, SEED_LEN};
+    use crate::signing_scheme::ToBytes;
+
+    let mut drbg = CtrDrbg::new(&e.seed);
+    let mut rng = ffi::Rng::from_seed(&drbg.bytes(SEED_LEN));
+    let (sk, pk) = ffi::keygen(deg, &mut rng).unwrap();
+    assert_eq!(pk.to_bytes(), e.pk, "count {}", e.count);
+    assert_eq!(sk.to_bytes(), e.sk, "count {}", e.count);
+
+    let nonce: [u8; NONCE_LEN] = drbg.bytes(NONCE_LEN).try_into().unwrap();
+    assert_eq!(nonce, t.bytes[1..1 + NONCE_LEN], "count {}", e.count);
+
+    // The verifier's hasher is the signer's too: SHAKE256 over the nonce, then the message
+    let mut hash = ffi::verify_start(t).unwrap();
+    hash.inject(&e.msg);
+    let mut rng = ffi::Rng::from_seed(&drbg.bytes(SEED_LEN));
+    let res = ffi::sign_finish(&mut rng, SigFormat::Compressed, &sk, hash, &nonce).unwrap();
+    assert_eq!(res.to_bytes(), t.to_bytes(), "count {}", e.count);
+}
+
+#[test]
+fn aes_256_matches_fips_197() {
+    let key: [u8; 32] = std::array::from_fn(|i| i as u8);
+    let pt = hex::decode("00112233445566778899aabbccddeeff").unwrap();
+    let ct = Aes256::new(&key).encrypt(&pt[..].try_into().unwrap());
+    assert_eq!(hex::encode(ct), "8ea2b7ca516745bfeafc49904b496089");
+}
+
+/// The generator seeds the DRBG with 0, 1, .. 47 and draws each entry's seed and message from
+/// it, so the first of both appear in every NIST signature KAT file.
+#[test]
+fn drbg_matches_the_kat_generator() {
+    let entropy: Vec<u8> = (0..48).collect();
+    let mut drbg = CtrDrbg::new(&entropy);
+
+    assert_eq!(
+        hex::encode_upper(drbg.bytes(48)),
+        "061550234D158C5EC95595FE04EF7A25767F2E24CC2BC479D09D86DC9ABCFDE7\
+         056A8C266F9EF97ED08541DBD2E1FFA1"
+    );
+    assert_eq!(
+        hex::encode_upper(drbg.bytes(33)),
+        "D81C4D8D734FCBFBEADE3D3F8A039FAA2A2C9957E835AD55B22E75BF57BB556AC8"
+    );
+}
+
+#[test]
+fn falcon512_kat() {
+    run_kat(Degree::F512, "falcon512-KAT.rsp");
+}
+
+#[test]
+fn falcon1024_kat() {
+    run_kat(Degree::F1024, "falcon1024-KAT.rsp");
+}
@@ -0,0 +1,181 @@
+//! Mutated and malformed keys and signatures must be rejected, with an error and not a panic.
+
+use std::io::Write;
+
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+
+use super::{Degree, Falcon, SigFormat, Signature, VerifyingKey};
+use crate::{
+    signing_scheme::{SigningScheme, ToBytes},
+    Error,
+};
+
+const FORMATS: [SigFormat; 3] = [
+    SigFormat::Compressed,
+    SigFormat::Padded,
+    SigFormat::ConstantTime,
+];
+
+const M: &[u8] = b"negative test message";
+
+/// Decodes and verifies, as a caller holding only bytes would.
+fn verify_bytes(falcon: &mut Falcon, pk: &[u8], t: &[u8]) -> Result<(), Error> {
+    let pk = VerifyingKey::try_from(pk)?;
+    let t = Signature::try_from(t)?;
+    falcon.verify(&pk, M, &t)
+}
+
+#[test]
+fn bit_flipped_signatures_are_rejected() {
+    for format in FORMATS {
+        let mut falcon = Falcon::new(Degree::F512, Some(b"bit flips")).with_format(format);
+        let (sk, pk) = falcon.keygen().unwrap();
+        let (pk, t) = (pk.to_bytes(), falcon.sign(&sk, M).unwrap().to_bytes());
+        assert_eq!(verify_bytes(&mut falcon, &pk, &t), Ok(()));
+
+        for bit in 0..8 * t.len() {
+            let mut mutant = t.clone();
+            mutant[bit / 8] ^= 1 << (bit % 8);
+            assert!(
+                verify_bytes(&mut falcon, &pk, &mutant).is_err(),
+                "{format:?}, bit {bit}"
+            );
+        }
+    }
+}
+
+#[test]
+fn truncated_and_extended_signatures_are_rejected() {
+    for format in FORMATS {
+        let mut falcon = Falcon::new(Degree::F512, Some(b"lengths")).with_format(format);
+        let (sk, pk) = falcon.keygen().unwrap();
+        let (pk, t) = (pk.to_bytes(), falcon.sign(&sk, M).unwrap().to_bytes());
+
+        for len in 0..t.len() {
+            assert!(
+                verify_bytes(&mut falcon, &pk, &t[..len]).is_err(),
+                "{format:?}, {len} bytes"
+            );
+        }
+
+        for extra in [0x00, 0x01, 0x80] {
+            let mut mutant = t.clone();
+            mutant.push(extra);
+            assert!(
+                verify_bytes(&mut falcon, &pk, &mutant).is_err(),
+                "{format:?}, extra {extra:#x}"
+            );
+        }
+    }
+}
+
+#[test]
+fn public_key_mutants_are_rejected() {
+    let mut falcon = Falcon::new(Degree::F512, Some(b"key flips"));
+    let (sk, pk) = falcon.keygen().unwrap();
+    let (pk, t) = (pk.to_bytes(), falcon.sign(&sk, M).unwrap().to_bytes());
+
+    for bit in 0..8 * pk.len() {
+        let mut mutant = pk.clone();
+        mutant[bit / 8] ^= 1 << (bit % 8);
+        assert!(verify_bytes(&mut falcon, &mutant, &t).is_err(), "bit {bit}");
+    }
+
+    // The first coefficient set to q, which is out of range
+    let mut mutant = pk.clone();
+    mutant[1] = (12289u16 >> 6) as u8;
+    mutant[2] = (mutant[2] & 0x03) | ((12289 & 0x3F) << 2) as u8;
+    assert_eq!(
+        verify_bytes(&mut falcon, &mutant, &t),
+        Err(Error::FalconFormat)
+    );
+}
+
+#[test]
+fn mismatched_keys_degrees_and_formats_are_rejected() {
+    let mut falcon = Falcon::new(Degree::F512, Some(b"mismatches"));
+    let (sk, pk) = falcon.keygen().unwrap();
+    let (_, other_pk) = falcon.keygen().unwrap();
+    let t = falcon.sign(&sk, M).unwrap();
+
+    assert_eq!(falcon.verify(&other_pk, M, &t), Err(Error::FalconBadSig));
+    assert_eq!(
+        falcon.verify(&pk, b"another message", &t),
+        Err(Error::FalconBadSig)
+    );
+
+    let mut falcon1024 = Falcon::new(Degree::F1024, Some(b"mismatches"));
+    let (sk1024, pk1024) = falcon1024.keygen().unwrap();
+    let t1024 = falcon1024.sign(&sk1024, M).unwrap();
+    let mismatch = Err(Error::DegreeMismatch {
+        expected: 9,
+        found: 10,
+    });
+
+    assert_eq!(falcon.verify(&pk1024, M, &t), mismatch);
+    assert_eq!(falcon.verify(&pk, M, &t1024), mismatch);
+    assert_eq!(falcon.sign(&sk1024, M).map(|_| ()), mismatch);
+    assert_eq!(
+        falcon1024.verify(&pk1024, M, &t),
+        Err(Error::DegreeMismatch {
+            expected: 10,
+            found: 9,
+        })
+    );
+
+    // A constant-time signature is not a compressed one, and the reverse
+    let mut ct = Falcon::new(Degree::F512, Some(b"formats")).with_format(SigFormat::ConstantTime);
+    let t_ct = ct.sign(&sk, M).unwrap();
+    assert_eq!(falcon.verify(&pk, M, &t_ct), Err(Error::FalconFormat));
+    assert_eq!(ct.verify(&pk, M, &t), Err(Error::FalconFormat));
+}
+
+#[test]
+fn streaming_and_batch_verification_reject_mutants() {
+    let mut falcon = Falcon::new(Degree::F512, Some(b"paths"));
+    let (sk, pk) = falcon.keygen().unwrap();
+    let t = falcon.sign(&sk, M).unwrap();
+
+    let mut bytes = t.to_bytes();
+    bytes[100] ^= 0x10;
+    let mutant = Signature::try_from(&bytes[..]).unwrap();
+
+    let mut verifier = falcon.verifier(&pk, &mutant).unwrap();
+    verifier.write_all(M).unwrap();
+    assert!(verifier.finish().is_err());
+
+    let items = [(&pk, M, &t), (&pk, M, &mutant), (&pk, &M[1..], &t)];
+    let res: Vec<_> = falcon
+        .verify_batch(&items)
+        .iter()
+        .map(Result::is_ok)
+        .collect();
+    assert_eq!(res, [true, false, false]);
+}
+
+/// Random bytes behind valid headers, at every plausible length, must fail cleanly.
+#[test]
+fn random_inputs_do_not_panic() {
+    let mut rng = ChaCha20Rng::seed_from_u64(0);
+    let deg = Degree::F512;
+    let mut falcon = Falcon::new(deg, Some(b"garbage"));
+    let (sk, pk) = falcon.keygen().unwrap();
+    let (pk, t) = (pk.to_bytes(), falcon.sign(&sk, M).unwrap().to_bytes());
+
+    for _ in 0..2000 {
+        let format = FORMATS[rng.gen_range(0..3)];
+        falcon = falcon.with_format(format);
+
+        let len = rng.gen_range(0..=deg.sig_size(SigFormat::Compressed) + 2);
+        let mut garbage: Vec<u8> = (0..len).map(|_| rng.gen()).collect();
+        if let Some(header) = garbage.first_mut() {
+            *header = [0x39, 0x59][rng.gen_range(0..2)];
+        }
+        assert!(verify_bytes(&mut falcon, &pk, &garbage).is_err());
+
+        let mut garbage: Vec<u8> = (0..pk.len()).map(|_| rng.gen()).collect();
+        garbage[0] = 0x09;
+        let _ = verify_bytes(&mut falcon, &garbage, &t);
+    }
+}
@@ -0,0 +1,658 @@
+//! Falcon, backed by either the C reference implementation (`falcon-c`, the default) or a
+//! pure-Rust port of it (`falcon-rust`). Keys and signatures are byte-compatible across the two.
+
+use crate::{
+    signing_scheme::{SchemeInfo, SigningScheme, ToBytes},
+    Error,
+};
+use rand_core::CryptoRngCore;
+use std::{
+    cell::RefCell,
+    fmt,
+    io::{self, Write},
+    sync::Mutex,
+    thread,
+};
+use thread_local::ThreadLocal;
+use zeroize::{Zeroize, Zeroizing};
+
+#[cfg(not(any(feature = "falcon-c", feature = "falcon-rust")))]
+compile_error!("Falcon needs a backend: enable the `falcon-c` or `falcon-rust` feature");
+
+// With both features on, `falcon-rust` is the one in use and the C library is only linked for
+// the cross-backend tests
+#[cfg(feature = "falcon-c")]
+#[cfg_attr(feature = "falcon-rust", allow(dead_code))]
+mod ffi;
+#[cfg(test)]
+mod kat;
+#[cfg(test)]
+mod malformed;
+#[cfg(feature = "falcon-rust")]
+mod native;
+#[cfg(test)]
+mod shared;
+#[cfg(all(test, feature = "falcon-c", feature = "falcon-rust"))]
+mod tests;
+
+#[cfg(not(feature = "falcon-rust"))]
+use ffi as backend;
+#[cfg(feature = "falcon-rust")]
+use native as backend;
+
+const NONCE_LEN: usize = 40;
+
+/// Bytes drawn from a caller's RNG to seed a SHAKE256 PRNG.
+const SEED_LEN: usize = 48;
+
+/// Falcon degree, stored as `logn` (the ring degree is `1 << logn`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Degree(u32);
+
+impl Degree {
+    pub const F512: Degree = Degree(9);
+    pub const F1024: Degree = Degree(10);
+
+    /// Any degree from 2 (`logn = 1`) up to 1024 (`logn = 10`). Degrees below 512 give no
+    /// meaningful security and are only meant for simulations and tests.
+    pub fn insecure(logn: u32) -> Result<Self, Error> {
+        match logn {
+            1..=10 => Ok(Degree(logn)),
+            _ => Err(Error::InvalidDegree(logn)),
+        }
+    }
+
+    pub fn logn(self) -> u32 {
+        self.0
+    }
+
+    pub fn n(self) -> usize {
+        1 << self.0
+    }
+
+    fn sk_size(self) -> usize {
+        let x = self.logn() as usize;
+        (if x <= 3 {
+            3usize << x
+        } else {
+            ((10usize - (x >> 1)) << (x - 2)) + (1 << x)
+        } + 1)
+    }
+
+    fn pk_size(self) -> usize {
+        let x = self.logn() as usize;
+        (if x <= 1 { 4usize } else { 7usize << (x - 2) } + 1)
+    }
+
+    fn sig_size(self, format: SigFormat) -> usize {
+        let x = self.logn() as usize;
+        match format {
+            SigFormat::Compressed => ((((11usize << x) + (101usize >> (10 - x))) + 7) >> 3) + 41,
+            SigFormat::Padded => {
+                44 + 3 * (256usize >> (10 - x))
+                    + 2 * (128usize >> (10 - x))
+                    + 3 * (64usize >> (10 - x))
+                    + 2 * (16usize >> (10 - x))
+                    - 2 * (2usize >> (10 - x))
+                    - 8 * (1usize >> (10 - x))
+            }
+            SigFormat::ConstantTime => (3usize << (x - 1)) - usize::from(x == 3) + 41,
+        }
+    }
+
+    /// Mean length of a compressed signature, measured over 4000 signatures under 20 keys.
+    fn mean_compressed_sig_size(self) -> usize {
+        [44, 46, 51, 60, 79, 118, 194, 348, 655, 1271][self.logn() as usize - 1]
+    }
+}
+
+/// Signature encoding, numbered as the `FALCON_SIG_*` constants.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SigFormat {
+    /// Variable length, smallest on average.
+    Compressed = 1,
+    /// Compressed, then zero-padded to a fixed length (666 bytes for Falcon-512, 1280 for
+    /// Falcon-1024) so that the length leaks nothing.
+    Padded = 2,
+    /// Fixed length, in the encoding the C library writes in constant time. The name is the
+    /// format's, not a promise about the code: the `falcon-rust` backend reads and writes the
+    /// same bytes without constant-time guarantees.
+    ConstantTime = 3,
+}
+
+pub struct Falcon {
+    deg: Degree,
+    format: SigFormat,
+    rng: backend::Rng,
+}
+
+impl fmt::Debug for Falcon {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Falcon")
+            .field("deg", &self.deg)
+            .field("format", &self.format)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Seeds a backend PRNG from a caller's RNG.
+fn rng_from(rng: &mut dyn CryptoRngCore) -> backend::Rng {
+    let mut seed = Zeroizing::new([0u8; SEED_LEN]);
+    rng.fill_bytes(&mut *seed);
+    backend::Rng::from_seed(&*seed)
+}
+
+impl Falcon {
+    pub fn new(deg: Degree, seed: Option<&[u8]>) -> Self {
+        let rng = match seed {
+            Some(seed) => backend::Rng::from_seed(seed),
+            None => backend::Rng::from_system(),
+        };
+
+        Self {
+            deg,
+            format: SigFormat::Compressed,
+            rng,
+        }
+    }
+
+    /// Like `new`, but seeds the signing PRNG from `rng`.
+    pub fn from_rng(deg: Degree, rng: &mut dyn CryptoRngCore) -> Self {
+        Self {
+            deg,
+            format: SigFormat::Compressed,
+            rng: rng_from(rng),
+        }
+    }
+
+    /// Signs and verifies using `format` instead of the default `SigFormat::Compressed`.
+    pub fn with_format(mut self, format: SigFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    pub fn format(&self) -> SigFormat {
+        self.format
+    }
+
+    pub fn degree(&self) -> Degree {
+        self.deg
+    }
+
+    /// Signature length in bytes: an upper bound for `SigFormat::Compressed`, exact otherwise.
+    pub fn sig_size(&self) -> usize {
+        self.deg.sig_size(self.format)
+    }
+
+    fn check_degree(&self, deg: Degree) -> Result<(), Error> {
+        check_degree(self.deg, deg)
+    }
+}
+
+fn check_degree(expected: Degree, found: Degree) -> Result<(), Error> {
+    if found == expected {
+        Ok(())
+    } else {
+        Err(Error::DegreeMismatch {
+            expected: expected.logn(),
+            found: found.logn(),
+        })
+    }
+}
+
+/// A private key with its LDL tree precomputed, for signing many messages with one key.
+pub struct ExpandedSigningKey {
+    deg: Degree,
+    key: backend::ExpandedKey,
+}
+
+impl ExpandedSigningKey {
+    pub fn degree(&self) -> Degree {
+        self.deg
+    }
+}
+
+impl fmt::Debug for ExpandedSigningKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExpandedSigningKey")
+            .field("deg", &self.deg)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Reads the degree from the header byte of an encoded key or signature, whose high nibble
+/// must be one of `tags`.
+fn read_header(value: &[u8], tags: &[u8], field: &'static str) -> Result<Degree, Error> {
+    match value.first() {
+        Some(header) if tags.contains(&(header & 0xF0)) => {
+            Degree::insecure(u32::from(header & 0x0F))
+                .map_err(|_| Error::Decode { field, offset: 0 })
+        }
+        _ => Err(Error::Decode { field, offset: 0 }),
+    }
+}
+
+/// Fails at the offset where `value` ran out, or where its excess bytes begin.
+fn check_len(value: &[u8], min: usize, max: usize, field: &'static str) -> Result<(), Error> {
+    if (min..=max).contains(&value.len()) {
+        Ok(())
+    } else {
+        Err(Error::Decode {
+            field,
+            offset: value.len().min(max),
+        })
+    }
+}
+
+#[derive(Clone)]
+pub struct SigningKey {
+    deg: Degree,
+    bytes: Vec<u8>,
+}
+
+impl SigningKey {
+    pub fn degree(&self) -> Degree {
+        self.deg
+    }
+}
+
+impl fmt::Debug for SigningKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SigningKey")
+            .field("deg", &self.deg)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Drop for SigningKey {
+    fn drop(&mut self) {
+        self.bytes.zeroize();
+    }
+}
+
+impl ToBytes for SigningKey {
+    fn to_bytes(&self) -> Vec<u8> {
+        self.bytes.clone()
+    }
+}
+
+impl TryFrom<&[u8]> for SigningKey {
+    type Error = Error;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        let field = "Falcon signing key";
+        let deg = read_header(value, &[0x50], field)?;
+        check_len(value, deg.sk_size(), deg.sk_size(), field)?;
+
+        Ok(SigningKey {
+            deg,
+            bytes: value.to_vec(),
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VerifyingKey {
+    deg: Degree,
+    bytes: Vec<u8>,
+}
+
+impl VerifyingKey {
+    pub fn degree(&self) -> Degree {
+        self.deg
+    }
+}
+
+impl ToBytes for VerifyingKey {
+    fn to_bytes(&self) -> Vec<u8> {
+        self.bytes.clone()
+    }
+}
+
+impl TryFrom<&[u8]> for VerifyingKey {
+    type Error = Error;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        let field = "Falcon verifying key";
+        let deg = read_header(value, &[0x00], field)?;
+        check_len(value, deg.pk_size(), deg.pk_size(), field)?;
+
+        Ok(VerifyingKey {
+            deg,
+            bytes: value.to_vec(),
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Signature {
+    deg: Degree,
+    bytes: Vec<u8>,
+}
+
+impl Signature {
+    pub fn degree(&self) -> Degree {
+        self.deg
+    }
+}
+
+impl ToBytes for Signature {
+    fn to_bytes(&self) -> Vec<u8> {
+        self.bytes.clone()
+    }
+}
+
+impl TryFrom<&[u8]> for Signature {
+    type Error = Error;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        let field = "Falcon signature";
+        let deg = read_header(value, &[0x30, 0x50], field)?;
+
+        // Compressed and padded signatures share a header; constant-time ones are fixed size
+        if value[0] & 0xF0 == 0x50 {
+            let size = deg.sig_size(SigFormat::ConstantTime);
+            check_len(value, size, size, field)?;
+        } else {
+            check_len(
+                value,
+                1 + NONCE_LEN + 1,
+                deg.sig_size(SigFormat::Compressed),
+                field,
+            )?;
+        }
+
+        Ok(Signature {
+            deg,
+            bytes: value.to_vec(),
+        })
+    }
+}
+
+impl SigningScheme for Falcon {
+    type SigningKey = SigningKey;
+
+    type VerifyingKey = VerifyingKey;
+
+    type Signature = Signature;
+
+    /// The typical compressed size is a measured mean; the other formats are fixed length.
+    fn info(&self) -> SchemeInfo {
+        let max_signature_size = self.sig_size();
+
+        SchemeInfo {
+            public_key_size: self.deg.pk_size(),
+            secret_key_size: self.deg.sk_size(),
+            max_signature_size,
+            typical_signature_size: match self.format {
+                SigFormat::Compressed => self.deg.mean_compressed_sig_size(),
+                _ => max_signature_size,
+            },
+            nist_level: match self.deg.logn() {
+                9 => Some(1),
+                10 => Some(5),
+                _ => None,
+            },
+        }
+    }
+
+    fn keygen(&mut self) -> Result<(Self::SigningKey, Self::VerifyingKey), Error> {
+        backend::keygen(self.deg, &mut self.rng)
+    }
+
+    fn keygen_with_rng(
+        &mut self,
+        rng: &mut dyn CryptoRngCore,
+    ) -> Result<(Self::SigningKey, Self::VerifyingKey), Error> {
+        backend::keygen(self.deg, &mut rng_from(rng))
+    }
+
+    fn public_key(&self, sk: &Self::SigningKey) -> Result<Self::VerifyingKey, Error> {
+        self.check_degree(sk.deg)?;
+        backend::make_public(sk)
+    }
+
+    fn sign(&mut self, sk: &Self::SigningKey, m: &[u8]) -> Result<Self::Signature, Error> {
+        self.check_degree(sk.deg)?;
+        backend::sign(&mut self.rng, self.format, sk, m)
+    }
+
+    fn verify(
+        &mut self,
+        pk: &Self::VerifyingKey,
+        m: &[u8],
+        t: &Self::Signature,
+    ) -> Result<(), Error> {
+        self.verify_shared(pk, m, t)
+    }
+
+    /// Splits the batch across one thread per available core.
+    fn verify_batch(
+        &mut self,
+        items: &[(&Self::VerifyingKey, &[u8], &Self::Signature)],
+    ) -> Vec<Result<(), Error>> {
+        let threads = thread::available_parallelism().map_or(1, |n| n.get());
+        let chunk = items.len().div_ceil(threads).max(1);
+        let this = &*self;
+
+        thread::scope(|scope| {
+            let handles: Vec<_> = items
+                .chunks(chunk)
+                .map(|chunk| {
+                    scope.spawn(move || {
+                        chunk
+                            .iter()
+                            .map(|(pk, m, t)| this.verify_shared(pk, m, t))
+                            .collect::<Vec<_>>()
+                    })
+                })
+                .collect();
+
+            handles
+                .into_iter()
+                .flat_map(|handle| handle.join().unwrap())
+                .collect()
+        })
+    }
+}
+
+impl Falcon {
+    /// Verification only reads the degree and format, so it can run from several threads.
+    fn verify_shared(&self, pk: &VerifyingKey, m: &[u8], t: &Signature) -> Result<(), Error> {
+        self.check_degree(pk.deg)?;
+        self.check_degree(t.deg)?;
+        backend::verify(self.format, pk, m, t)
+    }
+
+    /// Expands `sk` once so that `sign_expanded` skips the expansion `sign` redoes every call.
+    pub fn expand(&self, sk: &SigningKey) -> Result<ExpandedSigningKey, Error> {
+        self.check_degree(sk.deg)?;
+
+        Ok(ExpandedSigningKey {
+            deg: self.deg,
+            key: backend::expand(sk)?,
+        })
+    }
+
+    pub fn sign_expanded(
+        &mut self,
+        esk: &ExpandedSigningKey,
+        m: &[u8],
+    ) -> Result<Signature, Error> {
+        self.check_degree(esk.deg)?;
+        backend::sign_expanded(&mut self.rng, self.format, esk.deg, &esk.key, m)
+    }
+}
+
+/// Signs a message fed incrementally through `Write`, e.g. with `io::copy` from a file.
+/// The signature is identical in format to one made by `sign`.
+pub struct StreamSigner<'a> {
+    falcon: &'a mut Falcon,
+    sk: &'a SigningKey,
+    nonce: [u8; NONCE_LEN],
+    hash: backend::Hasher,
+}
+
+impl Write for StreamSigner<'_> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.hash.inject(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl StreamSigner<'_> {
+    pub fn finish(self) -> Result<Signature, Error> {
+        backend::sign_finish(
+            &mut self.falcon.rng,
+            self.falcon.format,
+            self.sk,
+            self.hash,
+            &self.nonce,
+        )
+    }
+}
+
+/// Verifies a message fed incrementally through `Write`.
+pub struct StreamVerifier<'a> {
+    pk: &'a VerifyingKey,
+    t: &'a Signature,
+    format: SigFormat,
+    hash: backend::Hasher,
+}
+
+impl Write for StreamVerifier<'_> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.hash.inject(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl StreamVerifier<'_> {
+    pub fn finish(self) -> Result<(), Error> {
+        backend::verify_finish(self.format, self.pk, self.t, self.hash)
+    }
+}
+
+impl Falcon {
+    pub fn signer<'a>(&'a mut self, sk: &'a SigningKey) -> Result<StreamSigner<'a>, Error> {
+        self.check_degree(sk.deg)?;
+
+        let (nonce, hash) = backend::sign_start(&mut self.rng)?;
+
+        Ok(StreamSigner {
+            falcon: self,
+            sk,
+            nonce,
+            hash,
+        })
+    }
+
+    pub fn verifier<'a>(
+        &self,
+        pk: &'a VerifyingKey,
+        t: &'a Signature,
+    ) -> Result<StreamVerifier<'a>, Error> {
+        self.check_degree(pk.deg)?;
+        self.check_degree(t.deg)?;
+
+        Ok(StreamVerifier {
+            pk,
+            t,
+            format: self.format,
+            hash: backend::verify_start(t)?,
+        })
+    }
+}
+
+/// A `Send + Sync` Falcon handle that signs through `&self`, for sharing one instance across a
+/// pool of worker threads. Each thread gets its own PRNG, seeded from the master PRNG of the
+/// `Falcon` it was made from the first time that thread signs, so signing never contends on a
+/// lock. With a seeded master the outputs still depend on the order threads first sign in.
+pub struct SharedFalcon {
+    deg: Degree,
+    format: SigFormat,
+    master: Mutex<backend::Rng>,
+    rngs: ThreadLocal<RefCell<backend::Rng>>,
+}
+
+// Sharing is the point of the type, so a backend PRNG that stops being `Send` must not compile
+const _: fn() = || {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<SharedFalcon>();
+};
+
+impl fmt::Debug for SharedFalcon {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SharedFalcon")
+            .field("deg", &self.deg)
+            .field("format", &self.format)
+            .finish_non_exhaustive()
+    }
+}
+
+impl From<Falcon> for SharedFalcon {
+    fn from(falcon: Falcon) -> Self {
+        Self {
+            deg: falcon.deg,
+            format: falcon.format,
+            master: Mutex::new(falcon.rng),
+            rngs: ThreadLocal::new(),
+        }
+    }
+}
+
+impl SharedFalcon {
+    pub fn format(&self) -> SigFormat {
+        self.format
+    }
+
+    pub fn degree(&self) -> Degree {
+        self.deg
+    }
+
+    /// Runs `f` with this thread's PRNG.
+    fn with_rng<T>(&self, f: impl FnOnce(&mut backend::Rng) -> T) -> T {
+        let rng = self.rngs.get_or(|| {
+            let mut seed = Zeroizing::new([0u8; SEED_LEN]);
+            // A panic while the lock was held cannot leave the PRNG half-updated
+            let mut master = self.master.lock().unwrap_or_else(|e| e.into_inner());
+            master.fill(&mut *seed);
+            RefCell::new(backend::Rng::from_seed(&*seed))
+        });
+
+        f(&mut rng.borrow_mut())
+    }
+
+    pub fn sign(&self, sk: &SigningKey, m: &[u8]) -> Result<Signature, Error> {
+        check_degree(self.deg, sk.deg)?;
+        self.with_rng(|rng| backend::sign(rng, self.format, sk, m))
+    }
+
+    pub fn sign_expanded(&self, esk: &ExpandedSigningKey, m: &[u8]) -> Result<Signature, Error> {
+        check_degree(self.deg, esk.deg)?;
+        self.with_rng(|rng| backend::sign_expanded(rng, self.format, esk.deg, &esk.key, m))
+    }
+
+    pub fn expand(&self, sk: &SigningKey) -> Result<ExpandedSigningKey, Error> {
+        check_degree(self.deg, sk.deg)?;
+
+        Ok(ExpandedSigningKey {
+            deg: self.deg,
+            key: backend::expand(sk)?,
+        })
+    }
+
+    pub fn verify(&self, pk: &VerifyingKey, m: &[u8], t: &Signature) -> Result<(), Error> {
+        check_degree(self.deg, pk.deg)?;
+        check_degree(self.deg, t.deg)?;
+        backend::verify(self.format, pk, m, t)
+    }
+}
@@ -0,0 +1,162 @@
+//! The reference implementation's bit-packed encodings. Decoders return the number of bytes
+//! read, and reject anything the C decoders reject, including non-zero padding bits.
+
+use super::modq::Q;
+
+/// Packs coefficients in `0..Q` into 14 bits each, as in public keys.
+pub(super) fn modq_encode(x: &[u16], out: &mut Vec<u8>) {
+    let (mut acc, mut acc_len) = (0u32, 0);
+
+    for &w in x {
+        acc = (acc << 14) | u32::from(w);
+        acc_len += 14;
+        while acc_len >= 8 {
+            acc_len -= 8;
+            out.push((acc >> acc_len) as u8);
+        }
+    }
+
+    if acc_len > 0 {
+        out.push((acc << (8 - acc_len)) as u8);
+    }
+}
+
+pub(super) fn modq_decode(x: &mut [u16], input: &[u8]) -> Option<usize> {
+    let len = (x.len() * 14).div_ceil(8);
+    let (mut acc, mut acc_len, mut u) = (0u32, 0, 0);
+
+    for &b in input.get(..len)? {
+        acc = (acc << 8) | u32::from(b);
+        acc_len += 8;
+        if acc_len >= 14 {
+            acc_len -= 14;
+            let w = (acc >> acc_len) & 0x3FFF;
+            if w >= Q {
+                return None;
+            }
+            x[u] = w as u16;
+            u += 1;
+        }
+    }
+
+    (acc & ((1 << acc_len) - 1) == 0).then_some(len)
+}
+
+/// Packs signed coefficients into `bits` bits each, as in private keys and constant-time
+/// signatures. Fails if a coefficient is outside `-(2^(bits-1) - 1)..=2^(bits-1) - 1`.
+pub(super) fn trim_encode(x: &[i16], bits: u32, out: &mut Vec<u8>) -> Option<()> {
+    let max = (1 << (bits - 1)) - 1;
+    if x.iter().any(|&v| !(-max..=max).contains(&v)) {
+        return None;
+    }
+
+    let mask = (1u32 << bits) - 1;
+    let (mut acc, mut acc_len) = (0u32, 0);
+
+    for &v in x {
+        acc = (acc << bits) | (v as u32 & mask);
+        acc_len += bits;
+        while acc_len >= 8 {
+            acc_len -= 8;
+            out.push((acc >> acc_len) as u8);
+        }
+    }
+
+    if acc_len > 0 {
+        out.push((acc << (8 - acc_len)) as u8);
+    }
+
+    Some(())
+}
+
+pub(super) fn trim_decode(x: &mut [i16], bits: u32, input: &[u8]) -> Option<usize> {
+    let len = (x.len() * bits as usize).div_ceil(8);
+    let (mask, sign) = ((1u32 << bits) - 1, 1u32 << (bits - 1));
+    let (mut acc, mut acc_len, mut u) = (0u32, 0, 0);
+
+    for &b in input.get(..len)? {
+        acc = (acc << 8) | u32::from(b);
+        acc_len += 8;
+        while acc_len >= bits && u < x.len() {
+            acc_len -= bits;
+            let w = (acc >> acc_len) & mask;
+            // -2^(bits-1) has no positive counterpart and is never produced
+            if w == sign {
+                return None;
+            }
+            x[u] = if w & sign != 0 {
+                w as i16 - (1 << bits)
+            } else {
+                w as i16
+            };
+            u += 1;
+        }
+    }
+
+    (acc & ((1 << acc_len) - 1) == 0).then_some(len)
+}
+
+/// Golomb-Rice-style compression of signature coefficients in `-2047..=2047`: a sign bit, the
+/// low 7 bits, then the high bits in unary.
+pub(super) fn comp_encode(x: &[i16], out: &mut Vec<u8>) -> Option<()> {
+    if x.iter().any(|&v| !(-2047..=2047).contains(&v)) {
+        return None;
+    }
+
+    let (mut acc, mut acc_len) = (0u32, 0);
+
+    for &v in x {
+        acc = (acc << 1) | u32::from(v < 0);
+        let w = u32::from(v.unsigned_abs());
+        acc = (acc << 7) | (w & 127);
+        let high = w >> 7;
+        acc = (acc << (high + 1)) | 1;
+        acc_len += 8 + high + 1;
+        while acc_len >= 8 {
+            acc_len -= 8;
+            out.push((acc >> acc_len) as u8);
+        }
+    }
+
+    if acc_len > 0 {
+        out.push((acc << (8 - acc_len)) as u8);
+    }
+
+    Some(())
+}
+
+pub(super) fn comp_decode(x: &mut [i16], input: &[u8]) -> Option<usize> {
+    let (mut acc, mut acc_len, mut v) = (0u32, 0, 0);
+
+    for coeff in x.iter_mut() {
+        acc = (acc << 8) | u32::from(*input.get(v)?);
+        v += 1;
+        let b = acc >> acc_len;
+        let negative = b & 128 != 0;
+        let mut m = b & 127;
+
+        loop {
+            if acc_len == 0 {
+                acc = (acc << 8) | u32::from(*input.get(v)?);
+                v += 1;
+                acc_len = 8;
+            }
+            acc_len -= 1;
+            if (acc >> acc_len) & 1 != 0 {
+                break;
+            }
+            m += 128;
+            if m > 2047 {
+                return None;
+            }
+        }
+
+        // "-0" is not a canonical encoding
+        if negative && m == 0 {
+            return None;
+        }
+        *coeff = if negative { -(m as i16) } else { m as i16 };
+    }
+
+    (acc & ((1 << acc_len) - 1) == 0).then_some(v)
+}
@@ -0,0 +1,205 @@
+//! Real polynomials modulo x^n + 1 in FFT representation: their values at the n complex roots
+//! of x^n + 1, in which multiplication is pointwise and the adjoint is conjugation.
+
+use std::{
+    f64::consts::PI,
+    ops::{Add, Div, Mul, Neg, Sub},
+    sync::OnceLock,
+};
+use zeroize::DefaultIsZeroes;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(super) struct Complex {
+    pub(super) re: f64,
+    pub(super) im: f64,
+}
+
+impl DefaultIsZeroes for Complex {}
+
+impl Complex {
+    pub(super) fn real(re: f64) -> Self {
+        Complex { re, im: 0.0 }
+    }
+
+    pub(super) fn conj(self) -> Self {
+        Complex {
+            re: self.re,
+            im: -self.im,
+        }
+    }
+
+    pub(super) fn norm_sqr(self) -> f64 {
+        self.re * self.re + self.im * self.im
+    }
+}
+
+impl Add for Complex {
+    type Output = Complex;
+
+    fn add(self, rhs: Complex) -> Complex {
+        Complex {
+            re: self.re + rhs.re,
+            im: self.im + rhs.im,
+        }
+    }
+}
+
+impl Sub for Complex {
+    type Output = Complex;
+
+    fn sub(self, rhs: Complex) -> Complex {
+        Complex {
+            re: self.re - rhs.re,
+            im: self.im - rhs.im,
+        }
+    }
+}
+
+impl Mul for Complex {
+    type Output = Complex;
+
+    fn mul(self, rhs: Complex) -> Complex {
+        Complex {
+            re: self.re * rhs.re - self.im * rhs.im,
+            im: self.re * rhs.im + self.im * rhs.re,
+        }
+    }
+}
+
+impl Mul<f64> for Complex {
+    type Output = Complex;
+
+    fn mul(self, rhs: f64) -> Complex {
+        Complex {
+            re: self.re * rhs,
+            im: self.im * rhs,
+        }
+    }
+}
+
+impl Div for Complex {
+    type Output = Complex;
+
+    fn div(self, rhs: Complex) -> Complex {
+        (self * rhs.conj()) * (1.0 / rhs.norm_sqr())
+    }
+}
+
+impl Neg for Complex {
+    type Output = Complex;
+
+    fn neg(self) -> Complex {
+        Complex {
+            re: -self.re,
+            im: -self.im,
+        }
+    }
+}
+
+/// The roots of x^n + 1, ordered so that roots `2i` and `2i + 1` are the two square roots of
+/// root `i` for n / 2. This is what lets `split` and `merge` work on FFT representations.
+fn roots(n: usize) -> &'static [Complex] {
+    static ROOTS: OnceLock<Vec<Vec<Complex>>> = OnceLock::new();
+
+    let roots = ROOTS.get_or_init(|| {
+        let mut angles = vec![vec![], vec![PI / 2.0, -PI / 2.0]];
+        for logn in 2..=10 {
+            let halved = angles[logn - 1]
+                .iter()
+                .flat_map(|&a| [a / 2.0, a / 2.0 + PI])
+                .collect();
+            angles.push(halved);
+        }
+
+        angles
+            .iter()
+            .map(|level| {
+                level
+                    .iter()
+                    .map(|&a| Complex {
+                        re: a.cos(),
+                        im: a.sin(),
+                    })
+                    .collect()
+            })
+            .collect()
+    });
+
+    &roots[n.trailing_zeros() as usize]
+}
+
+/// Splits `f(x) = f0(x^2) + x f1(x^2)` into `f0` and `f1`, all in FFT representation.
+pub(super) fn split(f: &[Complex]) -> (Vec<Complex>, Vec<Complex>) {
+    let w = roots(f.len());
+
+    f.chunks(2)
+        .zip(w.iter().step_by(2))
+        .map(|(pair, &w)| {
+            (
+                (pair[0] + pair[1]) * 0.5,
+                (pair[0] - pair[1]) * w.conj() * 0.5,
+            )
+        })
+        .unzip()
+}
+
+/// The inverse of `split`.
+pub(super) fn merge(f0: &[Complex], f1: &[Complex]) -> Vec<Complex> {
+    let w = roots(2 * f0.len());
+
+    f0.iter()
+        .zip(f1)
+        .zip(w.iter().step_by(2))
+        .flat_map(|((&a, &b), &w)| [a + w * b, a - w * b])
+        .collect()
+}
+
+pub(super) fn fft(f: &[f64]) -> Vec<Complex> {
+    if f.len() == 2 {
+        return vec![
+            Complex { re: f[0], im: f[1] },
+            Complex {
+                re: f[0],
+                im: -f[1],
+            },
+        ];
+    }
+
+    let f0: Vec<_> = f.iter().step_by(2).copied().collect();
+    let f1: Vec<_> = f.iter().skip(1).step_by(2).copied().collect();
+    merge(&fft(&f0), &fft(&f1))
+}
+
+pub(super) fn ifft(f: &[Complex]) -> Vec<f64> {
+    if f.len() == 2 {
+        return vec![f[0].re, f[0].im];
+    }
+
+    let (f0, f1) = split(f);
+    ifft(&f0)
+        .into_iter()
+        .zip(ifft(&f1))
+        .flat_map(|(a, b)| [a, b])
+        .collect()
+}
+
+pub(super) fn add(a: &[Complex], b: &[Complex]) -> Vec<Complex> {
+    a.iter().zip(b).map(|(&x, &y)| x + y).collect()
+}
+
+pub(super) fn sub(a: &[Complex], b: &[Complex]) -> Vec<Complex> {
+    a.iter().zip(b).map(|(&x, &y)| x - y).collect()
+}
+
+pub(super) fn mul(a: &[Complex], b: &[Complex]) -> Vec<Complex> {
+    a.iter().zip(b).map(|(&x, &y)| x * y).collect()
+}
+
+pub(super) fn div(a: &[Complex], b: &[Complex]) -> Vec<Complex> {
+    a.iter().zip(b).map(|(&x, &y)| x / y).collect()
+}
+
+/// `a * adj(b)`.
+pub(super) fn mul_adj(a: &[Complex], b: &[Complex]) -> Vec<Complex> {
+    a.iter().zip(b).map(|(&x, &y)| x * y.conj()).collect()
+}
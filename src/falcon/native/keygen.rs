@@ -0,0 +1,234 @@
+//! Key generation: short `f` and `g`, then `F` and `G` solving the NTRU equation
+//! `fG - gF = q`, found with exact big-integer arithmetic over the tower of field norms.
+
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_traits::{One, ToPrimitive, Zero};
+use zeroize::Zeroizing;
+
+use super::{
+    fft::{self, Complex},
+    modq::{self, Q},
+    Rng, MAX_BIG_FG_BITS, MAX_FG_BITS,
+};
+
+/// Squared norm bound on `(f, g)` and on its Gram-Schmidt counterpart: (1.17)^2 q.
+const BNORM_MAX: f64 = 16822.4121;
+
+pub(super) struct KeyPair {
+    pub(super) f: Zeroizing<Vec<i16>>,
+    pub(super) g: Zeroizing<Vec<i16>>,
+    pub(super) big_f: Zeroizing<Vec<i16>>,
+    pub(super) h: Vec<u16>,
+}
+
+pub(super) fn generate(logn: u32, rng: &mut Rng) -> KeyPair {
+    let n = 1 << logn;
+    let sigma = 1.17 * (f64::from(Q) / (2 * n) as f64).sqrt();
+    let lim = 1 << (MAX_FG_BITS[logn as usize] - 1);
+    let big_lim = (1 << (MAX_BIG_FG_BITS[logn as usize] - 1)) - 1;
+
+    loop {
+        let f = Zeroizing::new(sample_short(rng, n, sigma));
+        let g = Zeroizing::new(sample_short(rng, n, sigma));
+
+        if f.iter().chain(g.iter()).any(|&x| x >= lim || x <= -lim) {
+            continue;
+        }
+
+        let norm: i32 = f.iter().chain(g.iter()).map(|&x| i32::from(x).pow(2)).sum();
+        if f64::from(norm) >= BNORM_MAX || gram_schmidt_norm(&f, &g) >= BNORM_MAX {
+            continue;
+        }
+
+        let to_modq =
+            |x: &[i16]| -> Vec<u32> { x.iter().map(|&v| modq::reduce(v.into())).collect() };
+        let Some(h) = modq::div(&to_modq(&g), &to_modq(&f)) else {
+            continue;
+        };
+
+        // `G` is not stored, but is recomputed from the others and must fit the same bound
+        let Some((big_f, big_g)) = solve(&f, &g) else {
+            continue;
+        };
+        let (Some(big_f), Some(_)) = (narrow(&big_f, big_lim), narrow(&big_g, big_lim)) else {
+            continue;
+        };
+
+        return KeyPair {
+            f,
+            g,
+            big_f: Zeroizing::new(big_f),
+            h: h.into_iter().map(|x| x as u16).collect(),
+        };
+    }
+}
+
+/// Coefficients from a discrete Gaussian of deviation `sigma`, by rejection, with an odd sum so
+/// that the resultant of the polynomial with x^n + 1 is odd.
+fn sample_short(rng: &mut Rng, n: usize, sigma: f64) -> Vec<i16> {
+    let bound = (10.0 * sigma).ceil() as u64;
+    let mut sample = || loop {
+        let z = (rng.next_u64() % (2 * bound + 1)) as i64 - bound as i64;
+        if rng.next_f64() < (-((z * z) as f64) / (2.0 * sigma * sigma)).exp() {
+            break z as i16;
+        }
+    };
+
+    let mut res: Vec<i16> = (0..n).map(|_| sample()).collect();
+    while res.iter().map(|&x| i32::from(x)).sum::<i32>() % 2 == 0 {
+        res[n - 1] = sample();
+    }
+
+    res
+}
+
+/// Squared norm of `(q adj(f), q adj(g)) / (f adj(f) + g adj(g))`, the second Gram-Schmidt
+/// vector of the basis up to ordering.
+fn gram_schmidt_norm(f: &[i16], g: &[i16]) -> f64 {
+    let as_fft = |x: &[i16]| fft::fft(&x.iter().map(|&v| f64::from(v)).collect::<Vec<_>>());
+    let (f, g) = (as_fft(f), as_fft(g));
+    let den = fft::add(&fft::mul_adj(&f, &f), &fft::mul_adj(&g, &g));
+
+    [f, g]
+        .iter()
+        .flat_map(|x| {
+            let num: Vec<_> = x.iter().map(|&v| v.conj() * f64::from(Q)).collect();
+            fft::ifft(&fft::div(&num, &den))
+        })
+        .map(|v| v * v)
+        .sum()
+}
+
+/// `x` as small coefficients, unless one exceeds `lim` in absolute value.
+fn narrow(x: &[BigInt], lim: i16) -> Option<Vec<i16>> {
+    x.iter()
+        .map(|v| v.to_i16().filter(|v| (-lim..=lim).contains(v)))
+        .collect()
+}
+
+fn solve(f: &[i16], g: &[i16]) -> Option<(Vec<BigInt>, Vec<BigInt>)> {
+    let to_big = |x: &[i16]| -> Vec<BigInt> { x.iter().map(|&v| BigInt::from(v)).collect() };
+    solve_rec(&to_big(f), &to_big(g))
+}
+
+/// Solves for `f` and `g` of half the degree by taking field norms, lifts that solution back
+/// up, and reduces it against `(f, g)` to keep it short.
+fn solve_rec(f: &[BigInt], g: &[BigInt]) -> Option<(Vec<BigInt>, Vec<BigInt>)> {
+    if f.len() == 1 {
+        let gcd = f[0].extended_gcd(&g[0]);
+        if !gcd.gcd.is_one() {
+            return None;
+        }
+
+        let q = BigInt::from(Q);
+        return Some((vec![-&q * gcd.y], vec![q * gcd.x]));
+    }
+
+    let (big_f, big_g) = solve_rec(&field_norm(f), &field_norm(g))?;
+    let big_f = mul(&lift(&big_f), &conjugate(g));
+    let big_g = mul(&lift(&big_g), &conjugate(f));
+
+    Some(reduce(f, g, big_f, big_g))
+}
+
+/// Negacyclic product, skipping zero coefficients of `a` (half of them, for lifted inputs).
+fn mul(a: &[BigInt], b: &[BigInt]) -> Vec<BigInt> {
+    let n = a.len();
+    let mut res = vec![BigInt::zero(); n];
+
+    for (i, x) in a.iter().enumerate().filter(|(_, x)| !x.is_zero()) {
+        for (j, y) in b.iter().enumerate() {
+            if i + j < n {
+                res[i + j] += x * y;
+            } else {
+                res[i + j - n] -= x * y;
+            }
+        }
+    }
+
+    res
+}
+
+/// `f(x) f(-x)`, which only has even powers, as a polynomial in x^2.
+fn field_norm(f: &[BigInt]) -> Vec<BigInt> {
+    let even: Vec<_> = f.iter().step_by(2).cloned().collect();
+    let odd: Vec<_> = f.iter().skip(1).step_by(2).cloned().collect();
+    let (even, odd) = (mul(&even, &even), mul(&odd, &odd));
+    let m = even.len();
+
+    // even^2 - x odd^2, where multiplying by x rotates and negates the top coefficient
+    (0..m)
+        .map(|i| match i {
+            0 => &even[0] + &odd[m - 1],
+            _ => &even[i] - &odd[i - 1],
+        })
+        .collect()
+}
+
+/// `f(x^2)`.
+fn lift(f: &[BigInt]) -> Vec<BigInt> {
+    f.iter().flat_map(|x| [x.clone(), BigInt::zero()]).collect()
+}
+
+/// `f(-x)`.
+fn conjugate(f: &[BigInt]) -> Vec<BigInt> {
+    f.iter()
+        .enumerate()
+        .map(|(i, x)| if i % 2 == 0 { x.clone() } else { -x })
+        .collect()
+}
+
+fn max_bits(a: &[BigInt], b: &[BigInt]) -> u64 {
+    a.iter().chain(b).map(BigInt::bits).max().unwrap_or(0)
+}
+
+/// The top 53 bits of each coefficient, as FFT input, after dropping the low `shift` bits.
+fn approx(x: &[BigInt], shift: u64) -> Vec<Complex> {
+    fft::fft(
+        &x.iter()
+            .map(|v| (v >> shift).to_f64().unwrap_or(0.0))
+            .collect::<Vec<_>>(),
+    )
+}
+
+/// Babai reduction: subtracts `k (f, g)` from `(F, G)`, with `k` the rounded projection,
+/// computed on the top bits and scaled back up until `(F, G)` is no larger than `(f, g)`.
+fn reduce(
+    f: &[BigInt],
+    g: &[BigInt],
+    mut big_f: Vec<BigInt>,
+    mut big_g: Vec<BigInt>,
+) -> (Vec<BigInt>, Vec<BigInt>) {
+    let size = max_bits(f, g).max(53);
+    let (fa, ga) = (approx(f, size - 53), approx(g, size - 53));
+    let den = fft::add(&fft::mul_adj(&fa, &fa), &fft::mul_adj(&ga, &ga));
+
+    loop {
+        let big_size = max_bits(&big_f, &big_g).max(53);
+        if big_size < size {
+            break;
+        }
+
+        let (big_fa, big_ga) = (approx(&big_f, big_size - 53), approx(&big_g, big_size - 53));
+        let num = fft::add(&fft::mul_adj(&big_fa, &fa), &fft::mul_adj(&big_ga, &ga));
+        let k: Vec<BigInt> = fft::ifft(&fft::div(&num, &den))
+            .into_iter()
+            .map(|x| BigInt::from(x.round() as i64))
+            .collect();
+
+        if k.iter().all(BigInt::is_zero) {
+            break;
+        }
+
+        let shift = big_size - size;
+        for (x, y) in big_f.iter_mut().zip(mul(&k, f)) {
+            *x -= y << shift;
+        }
+        for (x, y) in big_g.iter_mut().zip(mul(&k, g)) {
+            *x -= y << shift;
+        }
+    }
+
+    (big_f, big_g)
+}
@@ -0,0 +1,449 @@
+//! A pure-Rust Falcon, following the reference implementation's encodings and checks so that
+//! keys and signatures are interchangeable with it. Unlike the C library, it makes no attempt
+//! to run in constant time, in any signature format: `SigFormat::ConstantTime` only selects
+//! the fixed-length encoding here.
+//!
+//! Secrets are wiped on drop as in the C backend: decoded and expanded keys, and the `Rng`
+//! sponge. `Hasher` is not, as it only sees the nonce and the message.
+
+mod codec;
+mod fft;
+mod keygen;
+mod modq;
+mod sign;
+
+use rand::{rngs::OsRng, RngCore};
+use sha3::{
+    digest::{ExtendableOutput, Update, XofReader},
+    Shake256,
+};
+use zeroize::{Zeroize, Zeroizing};
+
+use super::{Degree, SigFormat, Signature, SigningKey, VerifyingKey, NONCE_LEN, SEED_LEN};
+use crate::Error;
+
+pub(super) use sign::ExpandedKey;
+
+/// Bits per coefficient of `f` and `g` in an encoded private key, indexed by `logn`.
+const MAX_FG_BITS: [u32; 11] = [0, 8, 8, 8, 8, 8, 7, 7, 6, 6, 5];
+
+/// Bits per coefficient of `F` (and bound on `G`) in an encoded private key.
+const MAX_BIG_FG_BITS: [u32; 11] = [0, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8];
+
+/// Bits per coefficient of `s2` in a constant-time signature.
+const MAX_SIG_BITS: [u32; 11] = [0, 10, 11, 11, 12, 12, 12, 12, 12, 12, 12];
+
+/// Bound on the squared norm of `(s1, s2)`.
+const L2_BOUND: [u64; 11] = [
+    0, 101498, 208714, 428865, 892039, 1852696, 3842630, 7959734, 16468416, 34034726, 70265242,
+];
+
+/// SHAKE256's rate, in bytes.
+const RATE: usize = 136;
+
+/// A SHAKE256 PRNG, seeded as the C library's `shake256_init_prng_from_seed` is. It runs the
+/// sponge itself rather than through a `sha3` reader, whose buffered output could not be wiped,
+/// so that its whole state is cleared on drop.
+pub(super) struct Rng {
+    state: [u64; 25],
+    /// Bytes of the current output block already handed out.
+    pos: usize,
+}
+
+impl Rng {
+    pub(super) fn from_seed(seed: &[u8]) -> Self {
+        let mut rng = Rng {
+            state: [0; 25],
+            pos: 0,
+        };
+
+        for block in seed.chunks(RATE) {
+            rng.pos = 0;
+            for &b in block {
+                rng.xor_byte(b);
+            }
+            if block.len() == RATE {
+                keccak::f1600(&mut rng.state);
+            }
+        }
+
+        // SHAKE padding: domain bits 1111, then pad10*1
+        rng.pos = seed.len() % RATE;
+        rng.xor_byte(0x1F);
+        rng.pos = RATE - 1;
+        rng.xor_byte(0x80);
+        keccak::f1600(&mut rng.state);
+        rng.pos = 0;
+
+        rng
+    }
+
+    pub(super) fn from_system() -> Self {
+        let mut seed = Zeroizing::new([0u8; SEED_LEN]);
+        OsRng.fill_bytes(&mut *seed);
+        Self::from_seed(&*seed)
+    }
+
+    /// XORs `b` into the state at `pos`, and moves past it.
+    fn xor_byte(&mut self, b: u8) {
+        self.state[self.pos / 8] ^= u64::from(b) << (8 * (self.pos % 8));
+        self.pos += 1;
+    }
+
+    pub(super) fn fill(&mut self, buf: &mut [u8]) {
+        for b in buf {
+            if self.pos == RATE {
+                keccak::f1600(&mut self.state);
+                self.pos = 0;
+            }
+            *b = (self.state[self.pos / 8] >> (8 * (self.pos % 8))) as u8;
+            self.pos += 1;
+        }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut buf = [0; 8];
+        self.fill(&mut buf);
+        u64::from_le_bytes(buf)
+    }
+
+    /// Uniform in `[0, 1)`.
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+impl Drop for Rng {
+    fn drop(&mut self) {
+        self.state.zeroize();
+        self.pos = 0;
+    }
+}
+
+/// SHAKE256 over `nonce || message`, fed incrementally. Unlike `Rng` it is not wiped on drop:
+/// the nonce goes out in the signature and the message comes from the caller, so it holds
+/// nothing secret.
+pub(super) struct Hasher(Shake256);
+
+impl Hasher {
+    fn new(nonce: &[u8]) -> Self {
+        let mut shake = Shake256::default();
+        shake.update(nonce);
+        Hasher(shake)
+    }
+
+    pub(super) fn inject(&mut self, data: &[u8]) {
+        self.0.update(data);
+    }
+
+    /// Hashes to a point of Z_q[x]/(x^n + 1) by rejection sampling 16-bit values.
+    fn into_point(self, n: usize) -> Vec<u16> {
+        let mut xof = self.0.finalize_xof();
+        let mut res = Vec::with_capacity(n);
+
+        while res.len() < n {
+            let mut buf = [0; 2];
+            xof.read(&mut buf);
+            let w = u16::from_be_bytes(buf);
+            if u32::from(w) < 5 * modq::Q {
+                res.push(w % modq::Q as u16);
+            }
+        }
+
+        res
+    }
+}
+
+/// The private key's `f`, `g` and `F`; `G` is not encoded.
+struct PrivateKey {
+    f: Zeroizing<Vec<i16>>,
+    g: Zeroizing<Vec<i16>>,
+    big_f: Zeroizing<Vec<i16>>,
+}
+
+fn decode_private(sk: &SigningKey) -> Result<PrivateKey, Error> {
+    let logn = sk.deg.logn() as usize;
+    let n = sk.deg.n();
+
+    if sk.bytes.first() != Some(&(0x50 + logn as u8)) || sk.bytes.len() != sk.deg.sk_size() {
+        return Err(Error::FalconFormat);
+    }
+
+    let mut key = PrivateKey {
+        f: Zeroizing::new(vec![0; n]),
+        g: Zeroizing::new(vec![0; n]),
+        big_f: Zeroizing::new(vec![0; n]),
+    };
+
+    let mut pos = 1;
+    for (x, bits) in [
+        (&mut key.f, MAX_FG_BITS[logn]),
+        (&mut key.g, MAX_FG_BITS[logn]),
+        (&mut key.big_f, MAX_BIG_FG_BITS[logn]),
+    ] {
+        pos += codec::trim_decode(x, bits, &sk.bytes[pos..]).ok_or(Error::FalconFormat)?;
+    }
+
+    if pos != sk.bytes.len() {
+        return Err(Error::FalconFormat);
+    }
+
+    Ok(key)
+}
+
+fn to_modq(x: &[i16]) -> Vec<u32> {
+    x.iter().map(|&v| modq::reduce(v.into())).collect()
+}
+
+/// Recomputes `G = gF / f mod q`, which must be as short as `F`.
+fn complete_private(key: &PrivateKey, logn: usize) -> Result<Zeroizing<Vec<i16>>, Error> {
+    let big_g = modq::div(
+        &modq::mul(&to_modq(&key.g), &to_modq(&key.big_f)),
+        &to_modq(&key.f),
+    )
+    .ok_or(Error::FalconFormat)?;
+
+    let lim = (1 << (MAX_BIG_FG_BITS[logn] - 1)) - 1;
+    big_g
+        .into_iter()
+        .map(|w| {
+            let w = modq::center(w);
+            (-lim..=lim)
+                .contains(&w)
+                .then_some(w as i16)
+                .ok_or(Error::FalconFormat)
+        })
+        .collect::<Result<_, _>>()
+        .map(Zeroizing::new)
+}
+
+fn encode_public(deg: Degree, h: &[u16]) -> VerifyingKey {
+    let mut bytes = vec![deg.logn() as u8];
+    codec::modq_encode(h, &mut bytes);
+    VerifyingKey { deg, bytes }
+}
+
+pub(super) fn keygen(deg: Degree, rng: &mut Rng) -> Result<(SigningKey, VerifyingKey), Error> {
+    let logn = deg.logn() as usize;
+    let key = keygen::generate(deg.logn(), rng);
+
+    let mut sk = SigningKey {
+        deg,
+        bytes: vec![0x50 + logn as u8],
+    };
+    for (x, bits) in [
+        (&key.f, MAX_FG_BITS[logn]),
+        (&key.g, MAX_FG_BITS[logn]),
+        (&key.big_f, MAX_BIG_FG_BITS[logn]),
+    ] {
+        codec::trim_encode(x, bits, &mut sk.bytes).ok_or(Error::FalconInternal)?;
+    }
+
+    Ok((sk, encode_public(deg, &key.h)))
+}
+
+pub(super) fn make_public(sk: &SigningKey) -> Result<VerifyingKey, Error> {
+    let key = decode_private(sk)?;
+    let h = modq::div(&to_modq(&key.g), &to_modq(&key.f)).ok_or(Error::FalconFormat)?;
+    let h: Vec<u16> = h.into_iter().map(|x| x as u16).collect();
+
+    Ok(encode_public(sk.deg, &h))
+}
+
+pub(super) fn expand(sk: &SigningKey) -> Result<ExpandedKey, Error> {
+    let key = decode_private(sk)?;
+    let big_g = complete_private(&key, sk.deg.logn() as usize)?;
+
+    Ok(ExpandedKey::new(
+        sk.deg.logn(),
+        &key.f,
+        &key.g,
+        &key.big_f,
+        &big_g,
+    ))
+}
+
+pub(super) fn sign(
+    rng: &mut Rng,
+    format: SigFormat,
+    sk: &SigningKey,
+    m: &[u8],
+) -> Result<Signature, Error> {
+    let (nonce, mut hash) = sign_start(rng)?;
+    hash.inject(m);
+    sign_finish(rng, format, sk, hash, &nonce)
+}
+
+pub(super) fn sign_start(rng: &mut Rng) -> Result<([u8; NONCE_LEN], Hasher), Error> {
+    let mut nonce = [0; NONCE_LEN];
+    rng.fill(&mut nonce);
+    Ok((nonce, Hasher::new(&nonce)))
+}
+
+pub(super) fn sign_finish(
+    rng: &mut Rng,
+    format: SigFormat,
+    sk: &SigningKey,
+    hash: Hasher,
+    nonce: &[u8; NONCE_LEN],
+) -> Result<Signature, Error> {
+    let key = expand(sk)?;
+    Ok(sign_point(
+        rng,
+        format,
+        sk.deg,
+        &key,
+        &hash.into_point(sk.deg.n()),
+        nonce,
+    ))
+}
+
+pub(super) fn sign_expanded(
+    rng: &mut Rng,
+    format: SigFormat,
+    deg: Degree,
+    key: &ExpandedKey,
+    m: &[u8],
+) -> Result<Signature, Error> {
+    let (nonce, mut hash) = sign_start(rng)?;
+    hash.inject(m);
+    Ok(sign_point(
+        rng,
+        format,
+        deg,
+        key,
+        &hash.into_point(deg.n()),
+        &nonce,
+    ))
+}
+
+/// Samples until a signature of `c` is short enough and fits `format`.
+fn sign_point(
+    rng: &mut Rng,
+    format: SigFormat,
+    deg: Degree,
+    key: &ExpandedKey,
+    c: &[u16],
+    nonce: &[u8; NONCE_LEN],
+) -> Signature {
+    let logn = deg.logn() as usize;
+
+    loop {
+        let (s1, s2) = key.sample(c, rng);
+        if norm(&s1, &s2) > L2_BOUND[logn] {
+            continue;
+        }
+        // Short enough that every coefficient fits
+        let s2: Vec<i16> = s2.into_iter().map(|x| x as i16).collect();
+
+        let header = match format {
+            SigFormat::Compressed | SigFormat::Padded => 0x30,
+            SigFormat::ConstantTime => 0x50,
+        };
+        let mut bytes = vec![header + logn as u8];
+        bytes.extend(nonce);
+
+        let encoded = match format {
+            SigFormat::ConstantTime => codec::trim_encode(&s2, MAX_SIG_BITS[logn], &mut bytes),
+            _ => codec::comp_encode(&s2, &mut bytes),
+        };
+        let size = deg.sig_size(format);
+        if encoded.is_none() || bytes.len() > size {
+            continue;
+        }
+        if format == SigFormat::Padded {
+            bytes.resize(size, 0);
+        }
+
+        return Signature { deg, bytes };
+    }
+}
+
+fn norm(s1: &[i32], s2: &[i32]) -> u64 {
+    s1.iter()
+        .chain(s2)
+        .map(|&x| u64::from(x.unsigned_abs()).pow(2))
+        .sum()
+}
+
+pub(super) fn verify(
+    format: SigFormat,
+    pk: &VerifyingKey,
+    m: &[u8],
+    t: &Signature,
+) -> Result<(), Error> {
+    let mut hash = verify_start(t)?;
+    hash.inject(m);
+    verify_finish(format, pk, t, hash)
+}
+
+pub(super) fn verify_start(t: &Signature) -> Result<Hasher, Error> {
+    let nonce = t.bytes.get(1..1 + NONCE_LEN).ok_or(Error::FalconFormat)?;
+    Ok(Hasher::new(nonce))
+}
+
+pub(super) fn verify_finish(
+    format: SigFormat,
+    pk: &VerifyingKey,
+    t: &Signature,
+    hash: Hasher,
+) -> Result<(), Error> {
+    let deg = pk.deg;
+    let logn = deg.logn() as usize;
+    let n = deg.n();
+
+    if t.deg != deg {
+        return Err(Error::FalconBadSig);
+    }
+
+    let header = match format {
+        SigFormat::Compressed | SigFormat::Padded => 0x30,
+        SigFormat::ConstantTime => 0x50,
+    };
+    let fixed_size = match format {
+        SigFormat::Compressed => false,
+        SigFormat::Padded | SigFormat::ConstantTime => true,
+    };
+    if t.bytes[0] != header + logn as u8 || (fixed_size && t.bytes.len() != deg.sig_size(format)) {
+        return Err(Error::FalconFormat);
+    }
+
+    let mut h = vec![0; n];
+    if pk.bytes.len() != deg.pk_size()
+        || codec::modq_decode(&mut h, &pk.bytes[1..]) != Some(pk.bytes.len() - 1)
+    {
+        return Err(Error::FalconFormat);
+    }
+
+    let encoded = &t.bytes[1 + NONCE_LEN..];
+    let mut s2 = vec![0; n];
+    let len = match format {
+        SigFormat::ConstantTime => codec::trim_decode(&mut s2, MAX_SIG_BITS[logn], encoded),
+        _ => codec::comp_decode(&mut s2, encoded),
+    }
+    .ok_or(Error::FalconFormat)?;
+
+    // Only padding may follow the encoded `s2`, and only in padded signatures
+    let trailing = &encoded[len..];
+    if (format == SigFormat::Compressed && !trailing.is_empty()) || trailing.iter().any(|&b| b != 0)
+    {
+        return Err(Error::FalconFormat);
+    }
+
+    // s1 = c - s2 h, which must be short along with s2
+    let c = hash.into_point(n);
+    let h: Vec<u32> = h.into_iter().map(u32::from).collect();
+    let s2h = modq::mul(&to_modq(&s2), &h);
+    let s1: Vec<i32> = c
+        .iter()
+        .zip(s2h)
+        .map(|(&c, x)| modq::center(modq::reduce(i32::from(c) - x as i32)))
+        .collect();
+    let s2: Vec<i32> = s2.into_iter().map(i32::from).collect();
+
+    if norm(&s1, &s2) <= L2_BOUND[logn] {
+        Ok(())
+    } else {
+        Err(Error::FalconBadSig)
+    }
+}
@@ -0,0 +1,137 @@
+//! Arithmetic in Z_q[x]/(x^n + 1), with q = 12289.
+
+pub(super) const Q: u32 = 12289;
+
+/// A primitive 2048-th root of unity modulo `Q`, so that every `x^n + 1` up to n = 1024 splits.
+const ROOT: u32 = 7;
+
+fn pow(mut x: u32, mut e: u32) -> u32 {
+    let mut res = 1;
+
+    while e > 0 {
+        if e & 1 == 1 {
+            res = res * x % Q;
+        }
+        x = x * x % Q;
+        e >>= 1;
+    }
+
+    res
+}
+
+fn inv(x: u32) -> u32 {
+    pow(x, Q - 2)
+}
+
+/// In-place cyclic NTT of size `a.len()`, with `omega` a root of unity of that order.
+fn transform(a: &mut [u32], omega: u32) {
+    let n = a.len();
+
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            a.swap(i, j);
+        }
+    }
+
+    let mut len = 2;
+    while len <= n {
+        let step = pow(omega, (n / len) as u32);
+
+        for block in a.chunks_mut(len) {
+            let (lo, hi) = block.split_at_mut(len / 2);
+            let mut w = 1;
+
+            for (x, y) in lo.iter_mut().zip(hi) {
+                let u = *x;
+                let v = *y * w % Q;
+                *x = (u + v) % Q;
+                *y = (u + Q - v) % Q;
+                w = w * step % Q;
+            }
+        }
+
+        len <<= 1;
+    }
+}
+
+/// Evaluates `a` at the odd powers of a primitive 2n-th root of unity.
+fn ntt(a: &[u32]) -> Vec<u32> {
+    let n = a.len();
+    let psi = pow(ROOT, 2048 / (2 * n as u32));
+
+    let mut w = 1;
+    let mut res: Vec<u32> = a
+        .iter()
+        .map(|&x| {
+            let y = x * w % Q;
+            w = w * psi % Q;
+            y
+        })
+        .collect();
+
+    transform(&mut res, psi * psi % Q);
+    res
+}
+
+fn intt(mut a: Vec<u32>) -> Vec<u32> {
+    let n = a.len();
+    let psi_inv = inv(pow(ROOT, 2048 / (2 * n as u32)));
+
+    transform(&mut a, psi_inv * psi_inv % Q);
+
+    let mut w = inv(n as u32);
+    for x in a.iter_mut() {
+        *x = *x * w % Q;
+        w = w * psi_inv % Q;
+    }
+
+    a
+}
+
+/// Reduces a signed coefficient into `0..Q`.
+pub(super) fn reduce(x: i32) -> u32 {
+    x.rem_euclid(Q as i32) as u32
+}
+
+/// Lifts a coefficient in `0..Q` to `-Q/2..=Q/2`.
+pub(super) fn center(x: u32) -> i32 {
+    if x > Q / 2 {
+        x as i32 - Q as i32
+    } else {
+        x as i32
+    }
+}
+
+pub(super) fn mul(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let res = ntt(a)
+        .into_iter()
+        .zip(ntt(b))
+        .map(|(x, y)| x * y % Q)
+        .collect();
+
+    intt(res)
+}
+
+/// `a / b`, or `None` if `b` is not invertible.
+pub(super) fn div(a: &[u32], b: &[u32]) -> Option<Vec<u32>> {
+    let b = ntt(b);
+
+    if b.contains(&0) {
+        return None;
+    }
+
+    let res = ntt(a)
+        .into_iter()
+        .zip(b)
+        .map(|(x, y)| x * inv(y) % Q)
+        .collect();
+
+    Some(intt(res))
+}
@@ -0,0 +1,233 @@
+//! Signing: fast Fourier sampling of a short lattice vector close to the hashed message, over
+//! the LDL* tree of the secret basis `[[g, -f], [G, -F]]`.
+
+use zeroize::Zeroize;
+
+use super::{
+    fft::{self, Complex},
+    modq::Q,
+    Rng,
+};
+
+/// The smallest standard deviation `sample_z` is asked for, indexed by `logn`. The signing
+/// deviation is `1.17 sqrt(q)` times this.
+const SIGMA_MIN: [f64; 11] = [
+    0.0,
+    1.1165085072329102,
+    1.1321247692325271,
+    1.1475285353733669,
+    1.170254078853483,
+    1.1925466358390344,
+    1.214430050776614,
+    1.235926056771981,
+    1.2570545284063215,
+    1.2778336969128337,
+    1.298280334344292,
+];
+
+/// The deviation of the base sampler, which bounds every leaf of the tree.
+const SIGMA_MAX: f64 = 1.8205;
+
+/// Reverse cumulative distribution of the base half-Gaussian, scaled to 72 bits.
+const RCDT: [u128; 18] = [
+    3024686241123004913666,
+    1564742784480091954050,
+    636254429462080897535,
+    199560484645026482916,
+    47667343854657281903,
+    8595902006365044063,
+    1163297957344668388,
+    117656387352093658,
+    8867391802663976,
+    496969357462633,
+    20680885154299,
+    638331848991,
+    14602316184,
+    247426747,
+    3104126,
+    28824,
+    198,
+    1,
+];
+
+enum Tree {
+    /// The deviation to sample both coordinates of this leaf with.
+    Leaf(f64),
+    Node {
+        l10: Vec<Complex>,
+        left: Box<Tree>,
+        right: Box<Tree>,
+    },
+}
+
+impl Tree {
+    /// LDL* decomposition of the self-adjoint Gram matrix `[[g00, g01], [adj(g01), g11]]`,
+    /// recursing into the split halves of `D`.
+    fn new(g00: &[Complex], g01: &[Complex], g11: &[Complex], sigma: f64) -> Self {
+        let l10: Vec<_> = g01.iter().zip(g00).map(|(&a, &b)| a.conj() / b).collect();
+        let d11: Vec<_> = (0..g00.len())
+            .map(|i| g11[i] - Complex::real(l10[i].norm_sqr()) * g00[i])
+            .collect();
+
+        let child = |d: &[Complex]| {
+            if d.len() == 2 {
+                Tree::Leaf(sigma / d[0].re.sqrt())
+            } else {
+                let (d0, d1) = fft::split(d);
+                Tree::new(&d0, &d1, &d0, sigma)
+            }
+        };
+
+        Tree::Node {
+            left: Box::new(child(g00)),
+            right: Box::new(child(&d11)),
+            l10,
+        }
+    }
+
+    fn zeroize(&mut self) {
+        match self {
+            Tree::Leaf(sigma) => sigma.zeroize(),
+            Tree::Node { l10, left, right } => {
+                l10.zeroize();
+                left.zeroize();
+                right.zeroize();
+            }
+        }
+    }
+}
+
+/// The secret basis in FFT representation, with its normalised LDL* tree.
+pub(in crate::falcon) struct ExpandedKey {
+    logn: u32,
+    b00: Vec<Complex>,
+    b01: Vec<Complex>,
+    b10: Vec<Complex>,
+    b11: Vec<Complex>,
+    tree: Tree,
+}
+
+impl ExpandedKey {
+    pub(super) fn new(logn: u32, f: &[i16], g: &[i16], big_f: &[i16], big_g: &[i16]) -> Self {
+        let as_fft = |x: &[i16], sign: f64| {
+            fft::fft(&x.iter().map(|&v| sign * f64::from(v)).collect::<Vec<_>>())
+        };
+        let (b00, b01, b10, b11) = (
+            as_fft(g, 1.0),
+            as_fft(f, -1.0),
+            as_fft(big_g, 1.0),
+            as_fft(big_f, -1.0),
+        );
+
+        let g00 = fft::add(&fft::mul_adj(&b00, &b00), &fft::mul_adj(&b01, &b01));
+        let g01 = fft::add(&fft::mul_adj(&b00, &b10), &fft::mul_adj(&b01, &b11));
+        let g11 = fft::add(&fft::mul_adj(&b10, &b10), &fft::mul_adj(&b11, &b11));
+        let sigma = SIGMA_MIN[logn as usize] * 1.17 * f64::from(Q).sqrt();
+
+        ExpandedKey {
+            logn,
+            tree: Tree::new(&g00, &g01, &g11, sigma),
+            b00,
+            b01,
+            b10,
+            b11,
+        }
+    }
+
+    /// A candidate `(s1, s2)` with `s1 + s2 h = c`; the caller checks its norm.
+    pub(super) fn sample(&self, c: &[u16], rng: &mut Rng) -> (Vec<i32>, Vec<i32>) {
+        let c_fft = fft::fft(&c.iter().map(|&v| f64::from(v)).collect::<Vec<_>>());
+        let q_inv = 1.0 / f64::from(Q);
+
+        // The target c (1, 0) in the basis' coordinates
+        let t0: Vec<_> = fft::mul(&c_fft, &self.b11)
+            .into_iter()
+            .map(|v| v * q_inv)
+            .collect();
+        let t1: Vec<_> = fft::mul(&c_fft, &self.b01)
+            .into_iter()
+            .map(|v| v * -q_inv)
+            .collect();
+
+        let (z0, z1) = ff_sampling(&t0, &t1, &self.tree, self.logn, rng);
+
+        let v0 = fft::ifft(&fft::add(
+            &fft::mul(&z0, &self.b00),
+            &fft::mul(&z1, &self.b10),
+        ));
+        let v1 = fft::ifft(&fft::add(
+            &fft::mul(&z0, &self.b01),
+            &fft::mul(&z1, &self.b11),
+        ));
+
+        let s1 = c
+            .iter()
+            .zip(v0)
+            .map(|(&c, v)| i32::from(c) - v.round() as i32)
+            .collect();
+        let s2 = v1.into_iter().map(|v| -v.round() as i32).collect();
+
+        (s1, s2)
+    }
+}
+
+impl Drop for ExpandedKey {
+    fn drop(&mut self) {
+        self.b00.zeroize();
+        self.b01.zeroize();
+        self.b10.zeroize();
+        self.b11.zeroize();
+        self.tree.zeroize();
+    }
+}
+
+fn ff_sampling(
+    t0: &[Complex],
+    t1: &[Complex],
+    tree: &Tree,
+    logn: u32,
+    rng: &mut Rng,
+) -> (Vec<Complex>, Vec<Complex>) {
+    match tree {
+        Tree::Leaf(sigma) => (
+            vec![Complex::real(sample_z(t0[0].re, *sigma, logn, rng))],
+            vec![Complex::real(sample_z(t1[0].re, *sigma, logn, rng))],
+        ),
+        Tree::Node { l10, left, right } => {
+            let (a, b) = fft::split(t1);
+            let (a, b) = ff_sampling(&a, &b, right, logn, rng);
+            let z1 = fft::merge(&a, &b);
+
+            let t0 = fft::add(t0, &fft::mul(&fft::sub(t1, &z1), l10));
+            let (a, b) = fft::split(&t0);
+            let (a, b) = ff_sampling(&a, &b, left, logn, rng);
+            let z0 = fft::merge(&a, &b);
+
+            (z0, z1)
+        }
+    }
+}
+
+/// An integer from a discrete Gaussian centred on `mu` with deviation `sigma`: a base
+/// half-Gaussian sample, shifted and accepted with the ratio of the two densities.
+fn sample_z(mu: f64, sigma: f64, logn: u32, rng: &mut Rng) -> f64 {
+    let s = mu.floor();
+    let r = mu - s;
+    let dss = 1.0 / (2.0 * sigma * sigma);
+    let ccs = SIGMA_MIN[logn as usize] / sigma;
+
+    loop {
+        let mut u = [0u8; 16];
+        rng.fill(&mut u[..9]);
+        let u = u128::from_le_bytes(u);
+        let z0 = RCDT.iter().filter(|&&p| u < p).count() as f64;
+
+        let b = f64::from(rng.next_u64() as u8 & 1);
+        let z = b + (2.0 * b - 1.0) * z0;
+
+        let x = (z - r) * (z - r) * dss - z0 * z0 / (2.0 * SIGMA_MAX * SIGMA_MAX);
+        if rng.next_f64() < ccs * (-x).exp() {
+            return z + s;
+        }
+    }
+}
@@ -0,0 +1,56 @@
+//! One `SharedFalcon` must sign and verify from many threads at once.
+
+use std::thread;
+
+use super::{Degree, Falcon, SharedFalcon, NONCE_LEN};
+use crate::signing_scheme::SigningScheme;
+
+const THREADS: usize = 4;
+const PER_THREAD: usize = 3;
+
+#[test]
+fn threads_sign_and_verify_concurrently() {
+    let mut falcon = Falcon::new(Degree::F512, Some(b"shared test seed"));
+    let (sk, pk) = falcon.keygen().unwrap();
+    let shared = SharedFalcon::from(falcon);
+    let esk = shared.expand(&sk).unwrap();
+
+    let sigs: Vec<_> = thread::scope(|scope| {
+        let workers: Vec<_> = (0..THREADS)
+            .map(|i| {
+                let (shared, sk, esk, pk) = (&shared, &sk, &esk, &pk);
+                scope.spawn(move || {
+                    let mut sigs = vec![];
+                    for j in 0..PER_THREAD {
+                        let m = format!("thread {i} message {j}");
+                        let t = if j % 2 == 0 {
+                            shared.sign(sk, m.as_bytes()).unwrap()
+                        } else {
+                            shared.sign_expanded(esk, m.as_bytes()).unwrap()
+                        };
+                        assert_eq!(shared.verify(pk, m.as_bytes(), &t), Ok(()));
+                        sigs.push((m, t));
+                    }
+                    sigs
+                })
+            })
+            .collect();
+
+        workers
+            .into_iter()
+            .flat_map(|w| w.join().unwrap())
+            .collect()
+    });
+
+    // Every thread's signatures verify on this one, and no two threads drew the same nonce
+    let mut nonces: Vec<_> = sigs
+        .iter()
+        .map(|(m, t)| {
+            assert_eq!(shared.verify(&pk, m.as_bytes(), t), Ok(()));
+            t.bytes[1..1 + NONCE_LEN].to_vec()
+        })
+        .collect();
+    nonces.sort();
+    nonces.dedup();
+    assert_eq!(nonces.len(), THREADS * PER_THREAD);
+}
@@ -0,0 +1,125 @@
+//! Keys and signatures made by either backend must be accepted by the other.
+
+use super::{ffi, native, Degree, SigFormat};
+use crate::Error;
+
+const FORMATS: [SigFormat; 3] = [
+    SigFormat::Compressed,
+    SigFormat::Padded,
+    SigFormat::ConstantTime,
+];
+
+const M: &[u8] = b"cross-backend test message";
+
+fn degrees() -> [Degree; 3] {
+    [Degree::insecure(4).unwrap(), Degree::F512, Degree::F1024]
+}
+
+/// Seeds of every length class: empty, short, exactly one SHAKE256 block, and longer.
+#[test]
+fn prngs_give_the_same_stream() {
+    for len in [0, 48, 135, 136, 137, 300] {
+        let seed: Vec<u8> = (0..len).map(|i| i as u8).collect();
+        let mut rust = native::Rng::from_seed(&seed);
+        let mut c = ffi::Rng::from_seed(&seed);
+
+        // Odd read sizes, so reads straddle output blocks
+        for size in [1, 7, 135, 136, 300] {
+            let (mut a, mut b) = (vec![0; size], vec![0; size]);
+            rust.fill(&mut a);
+            c.fill(&mut b);
+            assert_eq!(a, b, "seed of {len} bytes");
+        }
+    }
+}
+
+#[test]
+fn rust_signatures_verify_with_c() {
+    let mut rng = native::Rng::from_seed(b"rust signs");
+
+    for deg in degrees() {
+        let (sk, pk) = native::keygen(deg, &mut rng).unwrap();
+
+        for format in FORMATS {
+            let t = native::sign(&mut rng, format, &sk, M).unwrap();
+            assert!(t.bytes.len() <= deg.sig_size(format));
+            assert_eq!(ffi::verify(format, &pk, M, &t), Ok(()));
+            assert_eq!(
+                ffi::verify(format, &pk, b"another message", &t),
+                Err(Error::FalconBadSig)
+            );
+        }
+    }
+}
+
+#[test]
+fn c_signatures_verify_with_rust() {
+    let mut rng = ffi::Rng::from_seed(b"c signs");
+
+    for deg in degrees() {
+        let (sk, pk) = ffi::keygen(deg, &mut rng).unwrap();
+
+        for format in FORMATS {
+            let t = ffi::sign(&mut rng, format, &sk, M).unwrap();
+            assert_eq!(native::verify(format, &pk, M, &t), Ok(()));
+            assert_eq!(
+                native::verify(format, &pk, b"another message", &t),
+                Err(Error::FalconBadSig)
+            );
+        }
+    }
+}
+
+#[test]
+fn keys_are_interchangeable() {
+    let mut c_rng = ffi::Rng::from_seed(b"c keys");
+    let mut rust_rng = native::Rng::from_seed(b"rust keys");
+
+    for deg in degrees() {
+        let (sk, pk) = ffi::keygen(deg, &mut c_rng).unwrap();
+        assert_eq!(native::make_public(&sk), Ok(pk.clone()));
+        let t = native::sign(&mut rust_rng, SigFormat::Compressed, &sk, M).unwrap();
+        assert_eq!(ffi::verify(SigFormat::Compressed, &pk, M, &t), Ok(()));
+
+        let (sk, pk) = native::keygen(deg, &mut rust_rng).unwrap();
+        assert_eq!(ffi::make_public(&sk), Ok(pk.clone()));
+        let t = ffi::sign(&mut c_rng, SigFormat::Compressed, &sk, M).unwrap();
+        assert_eq!(native::verify(SigFormat::Compressed, &pk, M, &t), Ok(()));
+    }
+}
+
+#[test]
+fn expanded_and_streamed_signatures_cross_verify() {
+    let mut c_rng = ffi::Rng::from_seed(b"c streams");
+    let mut rust_rng = native::Rng::from_seed(b"rust streams");
+    let deg = Degree::F512;
+    let (sk, pk) = ffi::keygen(deg, &mut c_rng).unwrap();
+    let (head, tail) = M.split_at(10);
+
+    for format in FORMATS {
+        let esk = native::expand(&sk).unwrap();
+        let t = native::sign_expanded(&mut rust_rng, format, deg, &esk, M).unwrap();
+        assert_eq!(ffi::verify(format, &pk, M, &t), Ok(()));
+
+        let esk = ffi::expand(&sk).unwrap();
+        let t = ffi::sign_expanded(&mut c_rng, format, deg, &esk, M).unwrap();
+        assert_eq!(native::verify(format, &pk, M, &t), Ok(()));
+
+        let (nonce, mut hash) = native::sign_start(&mut rust_rng).unwrap();
+        hash.inject(head);
+        hash.inject(tail);
+        let t = native::sign_finish(&mut rust_rng, format, &sk, hash, &nonce).unwrap();
+        let mut hash = ffi::verify_start(&t).unwrap();
+        hash.inject(head);
+        hash.inject(tail);
+        assert_eq!(ffi::verify_finish(format, &pk, &t, hash), Ok(()));
+
+        let (nonce, mut hash) = ffi::sign_start(&mut c_rng).unwrap();
+        hash.inject(M);
+        let t = ffi::sign_finish(&mut c_rng, format, &sk, hash, &nonce).unwrap();
+        let mut hash = native::verify_start(&t).unwrap();
+        hash.inject(head);
+        hash.inject(tail);
+        assert_eq!(native::verify_finish(format, &pk, &t, hash), Ok(()));
+    }
+}
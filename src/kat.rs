@@ -0,0 +1,88 @@
+//! Test vector files under `kat/`, an RNG that replays their seeds, and a roomier stack.
+
+use std::{collections::HashMap, fs, path::PathBuf};
+
+use rand_core::{impls, CryptoRng, RngCore};
+use serde_json::Value;
+
+/// The contents of `kat/<name>`. A missing file fails the test rather than skipping it.
+pub fn read(name: &str) -> String {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("kat")
+        .join(name);
+    fs::read_to_string(&path).unwrap_or_else(|e| panic!("{}: {e}", path.display()))
+}
+
+/// The test groups of a NIST ACVP or Wycheproof vector set.
+pub fn acvp_groups(name: &str) -> Vec<Value> {
+    let set: Value = serde_json::from_str(&read(name)).unwrap();
+    set["testGroups"].as_array().unwrap().clone()
+}
+
+/// The entries of a NIST `.rsp` file: blank-line separated runs of `key = value` lines, with
+/// `#` comment lines ignored.
+pub fn rsp(name: &str) -> Vec<HashMap<String, String>> {
+    let mut entries = vec![];
+    let mut fields = HashMap::new();
+
+    for line in read(name).lines().chain([""]) {
+        match line.split_once(" = ") {
+            Some((key, value)) => {
+                fields.insert(key.to_owned(), value.to_owned());
+            }
+            None if !fields.is_empty() => entries.push(std::mem::take(&mut fields)),
+            None => {}
+        }
+    }
+
+    entries
+}
+
+/// A hex string field of a JSON test case.
+pub fn hex(value: &Value) -> Vec<u8> {
+    hex::decode(value.as_str().unwrap()).unwrap()
+}
+
+/// Hands out the given bytes in order and panics once they run out, so a keygen that draws
+/// its seeds from an RNG reproduces a vector's keys exactly.
+pub struct Replay(Vec<u8>);
+
+impl Replay {
+    pub fn new(seeds: &[&[u8]]) -> Self {
+        Replay(seeds.concat())
+    }
+}
+
+impl RngCore for Replay {
+    fn next_u32(&mut self) -> u32 {
+        impls::next_u32_via_fill(self)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        impls::next_u64_via_fill(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        assert!(dest.len() <= self.0.len(), "replayed seeds exhausted");
+        dest.copy_from_slice(&self.0[..dest.len()]);
+        self.0.drain(..dest.len());
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl CryptoRng for Replay {}
+
+/// Runs `f` on a thread with a 16 MiB stack. ml-dsa keeps its matrices on the stack, and
+/// ML-DSA-87 key generation needs more than a test thread's 2 MiB in debug builds.
+pub fn on_large_stack(f: impl FnOnce() + Send + 'static) {
+    std::thread::Builder::new()
+        .stack_size(16 << 20)
+        .spawn(f)
+        .unwrap()
+        .join()
+        .unwrap();
+}
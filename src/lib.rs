@@ -1,4 +1,17 @@
+pub mod composite;
+pub mod ecdsa;
 pub mod eddsa;
+pub mod encoding;
+mod error;
 pub mod falcon;
+#[cfg(test)]
+mod kat;
+pub mod mldsa;
+pub mod registry;
+pub mod rsapss;
 pub mod signing_scheme;
+pub mod slhdsa;
+pub mod stateful;
 pub mod tls;
+
+pub use error::Error;
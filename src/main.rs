@@ -1,12 +1,24 @@
-use std::{io::Write, net::TcpStream, time::Instant};
+use std::{fs::File, io::Write, net::TcpStream, time::Instant};
 
 use ndarray_npy::write_npy;
 use netsim::simulator::{run, Endpoint};
-use pqsign::tls::{clientcache::ClientCachingTls, plain::PlainTls, pqc::PqcTls, pqccache::PqcWithCachingTls, Tls};
+use pqsign::composite::Composite;
+use pqsign::ecdsa::EcdsaP256;
+use pqsign::eddsa::Eddsa;
+use pqsign::falcon::{Degree, Falcon};
+use pqsign::mldsa::MlDsa44;
+use pqsign::rsapss::{RsaPss2048, RsaPss3072};
+use pqsign::signing_scheme::{SchemeInfo, SigningScheme};
+use pqsign::slhdsa::SlhDsaSha2_128s;
+use pqsign::tls::{clientcache::ClientCachingTls, hybrid::HybridTls, plain::PlainTls, pqc::PqcTls, pqccache::PqcWithCachingTls, Tls};
 use ndarray::Array2;
+use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
+
+/// Every key and signature in an experiment run is derived from this seed.
+const MASTER_SEED: u64 = 0;
 
 fn test_tls<T: Tls>() -> Array2<f64> {
-    let (mut cx, mut sx) = T::new();
+    let (mut cx, mut sx) = T::new(&mut ChaCha20Rng::seed_from_u64(MASTER_SEED)).unwrap();
 
     let mut e1: Endpoint = Box::new(|stream: &mut TcpStream| {
         T::client_transcript(&mut cx, stream);
@@ -23,15 +35,57 @@ fn test_tls<T: Tls>() -> Array2<f64> {
         T::server_certificate(&mut sx, stream);
         // println!("Server: sent certificate chain");
         stream.flush().unwrap();
-        T::server_certificate_verify(&mut sx, stream);
+        let verified = T::server_certificate_verify(&mut sx, stream);
         // println!("Server: sent certificate verify");
+        if !verified {
+            println!("Error: server rejected the client certificate!");
+        }
         stream.flush().unwrap();
     });
 
     run((100..2100).step_by(100), (0..200).step_by(10), 10, &mut e1, &mut e2)
 }
 
+/// Writes the key and signature sizes of the schemes under test, for the analysis scripts to
+/// read alongside the timing arrays.
+fn write_scheme_info(path: &str, schemes: &[(&str, SchemeInfo)]) -> std::io::Result<()> {
+    let mut file = File::create(path)?;
+    writeln!(file, "scheme,public_key_size,secret_key_size,max_signature_size,typical_signature_size,nist_level")?;
+
+    for (name, info) in schemes {
+        let level = info.nist_level.map_or(String::new(), |level| level.to_string());
+        writeln!(
+            file,
+            "{name},{},{},{},{},{level}",
+            info.public_key_size,
+            info.secret_key_size,
+            info.max_signature_size,
+            info.typical_signature_size,
+        )?;
+    }
+
+    Ok(())
+}
+
 fn main() {
+    write_scheme_info(
+        "out/scheme-info.csv",
+        &[
+            ("ed25519", Eddsa::new().info()),
+            ("ecdsa-p256", EcdsaP256::new().info()),
+            ("rsa-pss-2048", RsaPss2048::new().info()),
+            ("rsa-pss-3072", RsaPss3072::new().info()),
+            ("falcon-512", Falcon::new(Degree::F512, None).info()),
+            ("ml-dsa-44", MlDsa44::new().info()),
+            ("slh-dsa-sha2-128s", SlhDsaSha2_128s::new().info()),
+            (
+                "ed25519+falcon-512",
+                Composite::new(Eddsa::new(), Falcon::new(Degree::F512, None)).info(),
+            ),
+        ],
+    )
+    .unwrap();
+
     let now = Instant::now();
     let arr = test_tls::<ClientCachingTls>();
     println!("Client caching tls done: {} s", now.elapsed().as_secs_f64());
@@ -41,10 +95,34 @@ fn main() {
     println!("Plain tls done: {} s", now.elapsed().as_secs_f64());
     write_npy("out/plain-tls.npy", &arr).unwrap();
     let now = Instant::now();
+    let arr = test_tls::<PlainTls<EcdsaP256>>();
+    println!("Plain (ECDSA P-256) tls done: {} s", now.elapsed().as_secs_f64());
+    write_npy("out/plain-tls-ecdsa-p256.npy", &arr).unwrap();
+    let now = Instant::now();
+    let arr = test_tls::<PlainTls<RsaPss2048>>();
+    println!("Plain (RSA-PSS-2048) tls done: {} s", now.elapsed().as_secs_f64());
+    write_npy("out/plain-tls-rsa2048.npy", &arr).unwrap();
+    let now = Instant::now();
+    let arr = test_tls::<PlainTls<RsaPss3072>>();
+    println!("Plain (RSA-PSS-3072) tls done: {} s", now.elapsed().as_secs_f64());
+    write_npy("out/plain-tls-rsa3072.npy", &arr).unwrap();
+    let now = Instant::now();
     let arr = test_tls::<PqcTls>();
     println!("Pqc tls done: {} s", now.elapsed().as_secs_f64());
     write_npy("out/pqc-tls.npy", &arr).unwrap();
     let now = Instant::now();
+    let arr = test_tls::<HybridTls>();
+    println!("Hybrid tls done: {} s", now.elapsed().as_secs_f64());
+    write_npy("out/hybrid-tls.npy", &arr).unwrap();
+    let now = Instant::now();
+    let arr = test_tls::<PqcTls<MlDsa44>>();
+    println!("Pqc (ML-DSA-44) tls done: {} s", now.elapsed().as_secs_f64());
+    write_npy("out/pqc-tls-mldsa44.npy", &arr).unwrap();
+    let now = Instant::now();
+    let arr = test_tls::<PqcTls<Falcon, SlhDsaSha2_128s>>();
+    println!("Pqc (SLH-DSA root) tls done: {} s", now.elapsed().as_secs_f64());
+    write_npy("out/pqc-tls-slhdsa-root.npy", &arr).unwrap();
+    let now = Instant::now();
     let arr = test_tls::<PqcWithCachingTls>();
     println!("Pqc with caching tls done: {} s", now.elapsed().as_secs_f64());
     write_npy("out/pqc-with-caching.npy", &arr).unwrap();
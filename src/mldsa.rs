@@ -0,0 +1,415 @@
+use std::{fmt, marker::PhantomData, mem::size_of};
+
+use ml_dsa::{EncodedSignature, EncodedSigningKey, EncodedVerifyingKey, KeyGen, MlDsaParams};
+use rand::rngs::OsRng;
+use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
+use rand_core::CryptoRngCore;
+use zeroize::Zeroizing;
+
+use crate::{
+    registry::AlgorithmId,
+    signing_scheme::{SchemeInfo, SigningScheme, ToBytes},
+    Error,
+};
+
+pub type MlDsa44 = MlDsa<ml_dsa::MlDsa44>;
+pub type MlDsa65 = MlDsa<ml_dsa::MlDsa65>;
+pub type MlDsa87 = MlDsa<ml_dsa::MlDsa87>;
+
+/// Security category of a parameter set (FIPS 204, Table 1).
+pub trait Params: MlDsaParams {
+    const LEVEL: u8;
+    const ALGORITHM: AlgorithmId;
+}
+
+impl Params for ml_dsa::MlDsa44 {
+    const LEVEL: u8 = 2;
+    const ALGORITHM: AlgorithmId = AlgorithmId::MlDsa44;
+}
+
+impl Params for ml_dsa::MlDsa65 {
+    const LEVEL: u8 = 3;
+    const ALGORITHM: AlgorithmId = AlgorithmId::MlDsa65;
+}
+
+impl Params for ml_dsa::MlDsa87 {
+    const LEVEL: u8 = 5;
+    const ALGORITHM: AlgorithmId = AlgorithmId::MlDsa87;
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SigningMode {
+    /// Mixes fresh randomness into every signature, as FIPS 204 recommends.
+    Hedged,
+    /// Derives each signature from the key and message alone.
+    Deterministic,
+}
+
+/// ML-DSA (FIPS 204) with an empty context string.
+pub struct MlDsa<P> {
+    mode: SigningMode,
+    rng: ChaCha20Rng,
+    params: PhantomData<fn() -> P>,
+}
+
+impl<P: Params> MlDsa<P> {
+    /// Hedged signing, with randomness seeded from the OS.
+    pub fn new() -> Self {
+        Self::from_rng(&mut OsRng)
+    }
+
+    /// Like `new`, but seeds the hedging RNG from `rng`.
+    pub fn from_rng(rng: &mut dyn CryptoRngCore) -> Self {
+        let mut seed = Zeroizing::new([0u8; 32]);
+        rng.fill_bytes(&mut *seed);
+
+        Self {
+            mode: SigningMode::Hedged,
+            rng: ChaCha20Rng::from_seed(*seed),
+            params: PhantomData,
+        }
+    }
+
+    pub fn with_mode(mut self, mode: SigningMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    pub fn mode(&self) -> SigningMode {
+        self.mode
+    }
+}
+
+impl<P: Params> Default for MlDsa<P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Failing offset is where the input ran out, or where the trailing bytes begin.
+fn fixed_size<T: for<'a> TryFrom<&'a [u8]>>(value: &[u8], field: &'static str) -> Result<T, Error> {
+    T::try_from(value).map_err(|_| Error::Decode {
+        field,
+        offset: value.len().min(size_of::<T>()),
+    })
+}
+
+/// The encoded verifying key is kept alongside the signing key since ML-DSA private keys do
+/// not store `t1`, and recomputing it would take a full matrix multiplication.
+pub struct SigningKey<P: MlDsaParams> {
+    sk: ml_dsa::SigningKey<P>,
+    pk: EncodedVerifyingKey<P>,
+}
+
+impl<P: MlDsaParams> fmt::Debug for SigningKey<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SigningKey").finish_non_exhaustive()
+    }
+}
+
+/// Encoded as the FIPS 204 private key followed by the public key, for the reason above.
+impl<P: MlDsaParams> ToBytes for SigningKey<P> {
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut res = self.sk.encode().to_vec();
+        res.extend_from_slice(&self.pk);
+        res
+    }
+}
+
+impl<P: MlDsaParams> TryFrom<&[u8]> for SigningKey<P> {
+    type Error = Error;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        let field = "ML-DSA signing key";
+        let at = size_of::<EncodedSigningKey<P>>().min(value.len());
+        let (sk_bytes, pk_bytes) = value.split_at(at);
+
+        let sk: EncodedSigningKey<P> = fixed_size(sk_bytes, field)?;
+        let pk: EncodedVerifyingKey<P> =
+            fixed_size(pk_bytes, field).map_err(|_| Error::Decode {
+                field,
+                offset: value.len().min(at + size_of::<EncodedVerifyingKey<P>>()),
+            })?;
+
+        // Both halves start with the public seed `rho`
+        if sk_bytes[..32] != pk_bytes[..32] {
+            return Err(Error::Decode { field, offset: at });
+        }
+
+        Ok(SigningKey {
+            sk: ml_dsa::SigningKey::decode(&sk),
+            pk,
+        })
+    }
+}
+
+pub struct VerifyingKey<P: MlDsaParams>(ml_dsa::VerifyingKey<P>);
+
+impl<P: MlDsaParams> ToBytes for VerifyingKey<P> {
+    fn to_bytes(&self) -> Vec<u8> {
+        self.0.encode().to_vec()
+    }
+}
+
+impl<P: MlDsaParams> TryFrom<&[u8]> for VerifyingKey<P> {
+    type Error = Error;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        let enc: EncodedVerifyingKey<P> = fixed_size(value, "ML-DSA verifying key")?;
+        Ok(VerifyingKey(ml_dsa::VerifyingKey::decode(&enc)))
+    }
+}
+
+pub struct Signature<P: MlDsaParams>(ml_dsa::Signature<P>);
+
+impl<P: MlDsaParams> ToBytes for Signature<P> {
+    fn to_bytes(&self) -> Vec<u8> {
+        self.0.encode().to_vec()
+    }
+}
+
+impl<P: MlDsaParams> TryFrom<&[u8]> for Signature<P> {
+    type Error = Error;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        let enc: EncodedSignature<P> = fixed_size(value, "ML-DSA signature")?;
+        ml_dsa::Signature::decode(&enc)
+            .map(Signature)
+            .ok_or(Error::MlDsaFormat)
+    }
+}
+
+impl<P: Params> SigningScheme for MlDsa<P> {
+    type SigningKey = SigningKey<P>;
+
+    type VerifyingKey = VerifyingKey<P>;
+
+    type Signature = Signature<P>;
+
+    /// The secret key size counts the public key carried with it.
+    fn info(&self) -> SchemeInfo {
+        let signature_size = size_of::<EncodedSignature<P>>();
+
+        SchemeInfo {
+            public_key_size: size_of::<EncodedVerifyingKey<P>>(),
+            secret_key_size: size_of::<EncodedSigningKey<P>>()
+                + size_of::<EncodedVerifyingKey<P>>(),
+            max_signature_size: signature_size,
+            typical_signature_size: signature_size,
+            nist_level: Some(P::LEVEL),
+        }
+    }
+
+    fn keygen(&mut self) -> Result<(Self::SigningKey, Self::VerifyingKey), Error> {
+        self.keygen_with_rng(&mut OsRng)
+    }
+
+    fn keygen_with_rng(
+        &mut self,
+        rng: &mut dyn CryptoRngCore,
+    ) -> Result<(Self::SigningKey, Self::VerifyingKey), Error> {
+        let kp = P::key_gen(rng);
+
+        // The keys are not `Clone`, so they are copied out of the pair through their encodings
+        let sk = ml_dsa::SigningKey::decode(&kp.signing_key().encode());
+        let pk = kp.verifying_key().encode();
+        let vk = ml_dsa::VerifyingKey::decode(&pk);
+
+        Ok((SigningKey { sk, pk }, VerifyingKey(vk)))
+    }
+
+    fn public_key(&self, sk: &Self::SigningKey) -> Result<Self::VerifyingKey, Error> {
+        Ok(VerifyingKey(ml_dsa::VerifyingKey::decode(&sk.pk)))
+    }
+
+    fn sign(&mut self, sk: &Self::SigningKey, m: &[u8]) -> Result<Self::Signature, Error> {
+        let t = match self.mode {
+            SigningMode::Hedged => sk.sk.sign_randomized(m, &[], &mut self.rng),
+            SigningMode::Deterministic => sk.sk.sign_deterministic(m, &[]),
+        };
+
+        t.map(Signature).map_err(|_| Error::MlDsaContextLength)
+    }
+
+    fn verify(
+        &mut self,
+        pk: &Self::VerifyingKey,
+        m: &[u8],
+        t: &Self::Signature,
+    ) -> Result<(), Error> {
+        if pk.0.verify_with_context(m, &[], &t.0) {
+            Ok(())
+        } else {
+            Err(Error::MlDsaVerify)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ml_dsa::B32;
+    use serde_json::Value;
+
+    use super::*;
+    use crate::kat::{self, on_large_stack, Replay};
+
+    const M: &[u8] = b"ML-DSA round trip";
+
+    fn round_trip<P: Params>() {
+        let mut rng = ChaCha20Rng::seed_from_u64(0);
+        let mut scheme = MlDsa::<P>::from_rng(&mut rng);
+        let info = scheme.info();
+        let (sk, pk) = scheme.keygen_with_rng(&mut rng).unwrap();
+        let pk = pk.to_bytes();
+        assert_eq!(pk.len(), info.public_key_size);
+        assert_eq!(scheme.public_key(&sk).unwrap().to_bytes(), pk);
+
+        let encoded = sk.to_bytes();
+        assert_eq!(encoded.len(), info.secret_key_size);
+        let decoded = SigningKey::<P>::try_from(&encoded[..]).unwrap();
+        assert_eq!(decoded.to_bytes(), encoded);
+
+        // A public key that does not share the private key's `rho` is refused
+        let at = size_of::<EncodedSigningKey<P>>();
+        let mut mismatched = encoded.clone();
+        mismatched[at] ^= 1;
+        assert_eq!(
+            SigningKey::<P>::try_from(&mismatched[..]).map(|_| ()),
+            Err(Error::Decode {
+                field: "ML-DSA signing key",
+                offset: at
+            })
+        );
+
+        for mode in [SigningMode::Hedged, SigningMode::Deterministic] {
+            scheme = scheme.with_mode(mode);
+            let t = scheme.sign(&sk, M).unwrap().to_bytes();
+            assert_eq!(t.len(), info.max_signature_size);
+
+            let pk = VerifyingKey::<P>::try_from(&pk[..]).unwrap();
+            let decoded = Signature::<P>::try_from(&t[..]).unwrap();
+            assert_eq!(scheme.verify(&pk, M, &decoded), Ok(()));
+            assert_eq!(
+                scheme.verify(&pk, b"another message", &decoded),
+                Err(Error::MlDsaVerify)
+            );
+
+            let mut mutant = t.clone();
+            mutant[10] ^= 1;
+            let mutant = Signature::<P>::try_from(&mutant[..]).unwrap();
+            assert_eq!(scheme.verify(&pk, M, &mutant), Err(Error::MlDsaVerify));
+        }
+
+        // Deterministic signing is Sign_internal over `0 || len(ctx) || ctx || M` with rnd = 0
+        let t = scheme.sign(&sk, M).unwrap();
+        let internal = sk.sk.sign_internal(&[&[0, 0], M], &B32::default());
+        assert_eq!(t.to_bytes(), internal.encode().to_vec());
+
+        assert!(matches!(
+            VerifyingKey::<P>::try_from(&pk[1..]),
+            Err(Error::Decode { .. })
+        ));
+        assert!(matches!(
+            Signature::<P>::try_from(&t.to_bytes()[..info.max_signature_size - 1]),
+            Err(Error::Decode { .. })
+        ));
+    }
+
+    #[test]
+    fn round_trips() {
+        on_large_stack(|| {
+            round_trip::<ml_dsa::MlDsa44>();
+            round_trip::<ml_dsa::MlDsa65>();
+            round_trip::<ml_dsa::MlDsa87>();
+        });
+    }
+
+    #[test]
+    fn levels_follow_the_parameter_set() {
+        assert_eq!(MlDsa44::new().info().nist_level, Some(2));
+        assert_eq!(MlDsa65::new().info().nist_level, Some(3));
+        assert_eq!(MlDsa87::new().info().nist_level, Some(5));
+    }
+
+    /// Runs `check::<P>` on each ACVP test group, for the group's parameter set.
+    macro_rules! for_each_group {
+        ($file:expr, $check:ident) => {
+            for group in kat::acvp_groups($file) {
+                match group["parameterSet"].as_str().unwrap() {
+                    "ML-DSA-44" => $check::<ml_dsa::MlDsa44>(&group),
+                    "ML-DSA-65" => $check::<ml_dsa::MlDsa65>(&group),
+                    "ML-DSA-87" => $check::<ml_dsa::MlDsa87>(&group),
+                    other => panic!("unexpected parameter set {other}"),
+                }
+            }
+        };
+    }
+
+    /// Key generation draws the 32-byte seed `xi` from the RNG.
+    fn keygen<P: Params>(group: &Value) {
+        for t in group["tests"].as_array().unwrap() {
+            let mut rng = Replay::new(&[&kat::hex(&t["seed"])]);
+            let (sk, pk) = MlDsa::<P>::new().keygen_with_rng(&mut rng).unwrap();
+
+            assert_eq!(pk.to_bytes(), kat::hex(&t["pk"]), "tcId {}", t["tcId"]);
+            assert_eq!(
+                sk.sk.encode().to_vec(),
+                kat::hex(&t["sk"]),
+                "tcId {}",
+                t["tcId"]
+            );
+        }
+    }
+
+    fn sig_gen<P: Params>(group: &Value) {
+        for t in group["tests"].as_array().unwrap() {
+            let sk: EncodedSigningKey<P> = fixed_size(&kat::hex(&t["sk"]), "sk").unwrap();
+            let sk = ml_dsa::SigningKey::<P>::decode(&sk);
+            let rnd = match group["deterministic"].as_bool().unwrap() {
+                true => B32::default(),
+                false => fixed_size(&kat::hex(&t["rnd"]), "rnd").unwrap(),
+            };
+
+            let t_ = sk.sign_internal(&[&kat::hex(&t["message"])], &rnd);
+            assert_eq!(
+                t_.encode().to_vec(),
+                kat::hex(&t["signature"]),
+                "tcId {}",
+                t["tcId"]
+            );
+        }
+    }
+
+    fn sig_ver<P: Params>(group: &Value) {
+        let pk = VerifyingKey::<P>::try_from(&kat::hex(&group["pk"])[..]).unwrap();
+
+        for t in group["tests"].as_array().unwrap() {
+            let m = kat::hex(&t["message"]);
+            let valid = Signature::<P>::try_from(&kat::hex(&t["signature"])[..])
+                .is_ok_and(|sig| pk.0.verify_internal(&[&m], &sig.0));
+
+            assert_eq!(
+                valid,
+                t["testPassed"].as_bool().unwrap(),
+                "tcId {}: {}",
+                t["tcId"],
+                t["reason"]
+            );
+        }
+    }
+
+    #[test]
+    fn fips204_key_generation() {
+        on_large_stack(|| for_each_group!("ML-DSA-keyGen-FIPS204.json", keygen));
+    }
+
+    #[test]
+    fn fips204_signature_generation() {
+        for_each_group!("ML-DSA-sigGen-FIPS204.json", sig_gen);
+    }
+
+    #[test]
+    fn fips204_signature_verification() {
+        for_each_group!("ML-DSA-sigVer-FIPS204.json", sig_ver);
+    }
+}
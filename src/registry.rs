@@ -0,0 +1,409 @@
+use std::{collections::HashMap, fmt, str::FromStr};
+
+use rand::rngs::OsRng;
+use rand_core::CryptoRngCore;
+
+use crate::{
+    composite::Composite,
+    ecdsa::{EcdsaP256, EcdsaP384},
+    eddsa::Eddsa,
+    falcon::{Degree, Falcon},
+    mldsa::{MlDsa44, MlDsa65, MlDsa87},
+    rsapss::{RsaPss2048, RsaPss3072, RsaPss4096},
+    signing_scheme::DynSigningScheme,
+    slhdsa::{
+        SlhDsaSha2_128f, SlhDsaSha2_128s, SlhDsaSha2_192f, SlhDsaSha2_192s, SlhDsaSha2_256f,
+        SlhDsaSha2_256s, SlhDsaShake128f, SlhDsaShake128s, SlhDsaShake192f, SlhDsaShake192s,
+        SlhDsaShake256f, SlhDsaShake256s,
+    },
+    Error,
+};
+
+/// Identifies a signature algorithm in certificates and manifests.
+///
+/// The numeric codes and names are part of encoded data: never renumber or rename a variant,
+/// only add new ones.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u16)]
+pub enum AlgorithmId {
+    Ed25519 = 0x0001,
+    EcdsaP256 = 0x0002,
+    EcdsaP384 = 0x0003,
+    RsaPss2048 = 0x0004,
+    RsaPss3072 = 0x0005,
+    RsaPss4096 = 0x0006,
+
+    Falcon512 = 0x0101,
+    Falcon1024 = 0x0102,
+    MlDsa44 = 0x0103,
+    MlDsa65 = 0x0104,
+    MlDsa87 = 0x0105,
+    SlhDsaSha2_128s = 0x0110,
+    SlhDsaSha2_128f = 0x0111,
+    SlhDsaSha2_192s = 0x0112,
+    SlhDsaSha2_192f = 0x0113,
+    SlhDsaSha2_256s = 0x0114,
+    SlhDsaSha2_256f = 0x0115,
+    SlhDsaShake128s = 0x0116,
+    SlhDsaShake128f = 0x0117,
+    SlhDsaShake192s = 0x0118,
+    SlhDsaShake192f = 0x0119,
+    SlhDsaShake256s = 0x011a,
+    SlhDsaShake256f = 0x011b,
+
+    Ed25519Falcon512 = 0x0201,
+    Ed25519MlDsa65 = 0x0202,
+}
+
+impl AlgorithmId {
+    pub const ALL: [AlgorithmId; 25] = [
+        AlgorithmId::Ed25519,
+        AlgorithmId::EcdsaP256,
+        AlgorithmId::EcdsaP384,
+        AlgorithmId::RsaPss2048,
+        AlgorithmId::RsaPss3072,
+        AlgorithmId::RsaPss4096,
+        AlgorithmId::Falcon512,
+        AlgorithmId::Falcon1024,
+        AlgorithmId::MlDsa44,
+        AlgorithmId::MlDsa65,
+        AlgorithmId::MlDsa87,
+        AlgorithmId::SlhDsaSha2_128s,
+        AlgorithmId::SlhDsaSha2_128f,
+        AlgorithmId::SlhDsaSha2_192s,
+        AlgorithmId::SlhDsaSha2_192f,
+        AlgorithmId::SlhDsaSha2_256s,
+        AlgorithmId::SlhDsaSha2_256f,
+        AlgorithmId::SlhDsaShake128s,
+        AlgorithmId::SlhDsaShake128f,
+        AlgorithmId::SlhDsaShake192s,
+        AlgorithmId::SlhDsaShake192f,
+        AlgorithmId::SlhDsaShake256s,
+        AlgorithmId::SlhDsaShake256f,
+        AlgorithmId::Ed25519Falcon512,
+        AlgorithmId::Ed25519MlDsa65,
+    ];
+
+    pub fn code(self) -> u16 {
+        self as u16
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            AlgorithmId::Ed25519 => "ed25519",
+            AlgorithmId::EcdsaP256 => "ecdsa-p256",
+            AlgorithmId::EcdsaP384 => "ecdsa-p384",
+            AlgorithmId::RsaPss2048 => "rsa-pss-2048",
+            AlgorithmId::RsaPss3072 => "rsa-pss-3072",
+            AlgorithmId::RsaPss4096 => "rsa-pss-4096",
+            AlgorithmId::Falcon512 => "falcon-512",
+            AlgorithmId::Falcon1024 => "falcon-1024",
+            AlgorithmId::MlDsa44 => "ml-dsa-44",
+            AlgorithmId::MlDsa65 => "ml-dsa-65",
+            AlgorithmId::MlDsa87 => "ml-dsa-87",
+            AlgorithmId::SlhDsaSha2_128s => "slh-dsa-sha2-128s",
+            AlgorithmId::SlhDsaSha2_128f => "slh-dsa-sha2-128f",
+            AlgorithmId::SlhDsaSha2_192s => "slh-dsa-sha2-192s",
+            AlgorithmId::SlhDsaSha2_192f => "slh-dsa-sha2-192f",
+            AlgorithmId::SlhDsaSha2_256s => "slh-dsa-sha2-256s",
+            AlgorithmId::SlhDsaSha2_256f => "slh-dsa-sha2-256f",
+            AlgorithmId::SlhDsaShake128s => "slh-dsa-shake-128s",
+            AlgorithmId::SlhDsaShake128f => "slh-dsa-shake-128f",
+            AlgorithmId::SlhDsaShake192s => "slh-dsa-shake-192s",
+            AlgorithmId::SlhDsaShake192f => "slh-dsa-shake-192f",
+            AlgorithmId::SlhDsaShake256s => "slh-dsa-shake-256s",
+            AlgorithmId::SlhDsaShake256f => "slh-dsa-shake-256f",
+            AlgorithmId::Ed25519Falcon512 => "ed25519+falcon-512",
+            AlgorithmId::Ed25519MlDsa65 => "ed25519+ml-dsa-65",
+        }
+    }
+}
+
+impl TryFrom<u16> for AlgorithmId {
+    type Error = Error;
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        Self::ALL
+            .into_iter()
+            .find(|id| id.code() == value)
+            .ok_or(Error::UnknownAlgorithm)
+    }
+}
+
+impl FromStr for AlgorithmId {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|id| id.name() == s)
+            .ok_or(Error::UnknownAlgorithm)
+    }
+}
+
+impl fmt::Display for AlgorithmId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Builds a scheme, seeding any randomness it signs with from the given RNG.
+pub type Constructor = fn(&mut dyn CryptoRngCore) -> Box<dyn DynSigningScheme>;
+
+/// Resolves an `AlgorithmId` to a scheme instance.
+pub struct Registry {
+    constructors: HashMap<AlgorithmId, Constructor>,
+}
+
+impl Registry {
+    /// Every built-in scheme, with default settings.
+    pub fn new() -> Self {
+        let mut registry = Self {
+            constructors: HashMap::new(),
+        };
+
+        registry.register(AlgorithmId::Ed25519, |_| Box::new(Eddsa::new()));
+        registry.register(AlgorithmId::EcdsaP256, |_| Box::new(EcdsaP256::new()));
+        registry.register(AlgorithmId::EcdsaP384, |_| Box::new(EcdsaP384::new()));
+        registry.register(AlgorithmId::RsaPss2048, |rng| {
+            Box::new(RsaPss2048::from_rng(rng))
+        });
+        registry.register(AlgorithmId::RsaPss3072, |rng| {
+            Box::new(RsaPss3072::from_rng(rng))
+        });
+        registry.register(AlgorithmId::RsaPss4096, |rng| {
+            Box::new(RsaPss4096::from_rng(rng))
+        });
+        registry.register(AlgorithmId::Falcon512, |rng| {
+            Box::new(Falcon::from_rng(Degree::F512, rng))
+        });
+        registry.register(AlgorithmId::Falcon1024, |rng| {
+            Box::new(Falcon::from_rng(Degree::F1024, rng))
+        });
+        registry.register(AlgorithmId::MlDsa44, |rng| Box::new(MlDsa44::from_rng(rng)));
+        registry.register(AlgorithmId::MlDsa65, |rng| Box::new(MlDsa65::from_rng(rng)));
+        registry.register(AlgorithmId::MlDsa87, |rng| Box::new(MlDsa87::from_rng(rng)));
+        registry.register(AlgorithmId::SlhDsaSha2_128s, |rng| {
+            Box::new(SlhDsaSha2_128s::from_rng(rng))
+        });
+        registry.register(AlgorithmId::SlhDsaSha2_128f, |rng| {
+            Box::new(SlhDsaSha2_128f::from_rng(rng))
+        });
+        registry.register(AlgorithmId::SlhDsaSha2_192s, |rng| {
+            Box::new(SlhDsaSha2_192s::from_rng(rng))
+        });
+        registry.register(AlgorithmId::SlhDsaSha2_192f, |rng| {
+            Box::new(SlhDsaSha2_192f::from_rng(rng))
+        });
+        registry.register(AlgorithmId::SlhDsaSha2_256s, |rng| {
+            Box::new(SlhDsaSha2_256s::from_rng(rng))
+        });
+        registry.register(AlgorithmId::SlhDsaSha2_256f, |rng| {
+            Box::new(SlhDsaSha2_256f::from_rng(rng))
+        });
+        registry.register(AlgorithmId::SlhDsaShake128s, |rng| {
+            Box::new(SlhDsaShake128s::from_rng(rng))
+        });
+        registry.register(AlgorithmId::SlhDsaShake128f, |rng| {
+            Box::new(SlhDsaShake128f::from_rng(rng))
+        });
+        registry.register(AlgorithmId::SlhDsaShake192s, |rng| {
+            Box::new(SlhDsaShake192s::from_rng(rng))
+        });
+        registry.register(AlgorithmId::SlhDsaShake192f, |rng| {
+            Box::new(SlhDsaShake192f::from_rng(rng))
+        });
+        registry.register(AlgorithmId::SlhDsaShake256s, |rng| {
+            Box::new(SlhDsaShake256s::from_rng(rng))
+        });
+        registry.register(AlgorithmId::SlhDsaShake256f, |rng| {
+            Box::new(SlhDsaShake256f::from_rng(rng))
+        });
+        registry.register(AlgorithmId::Ed25519Falcon512, |rng| {
+            Box::new(Composite::new(
+                Eddsa::new(),
+                Falcon::from_rng(Degree::F512, rng),
+            ))
+        });
+        registry.register(AlgorithmId::Ed25519MlDsa65, |rng| {
+            Box::new(Composite::new(Eddsa::new(), MlDsa65::from_rng(rng)))
+        });
+
+        registry
+    }
+
+    /// Replaces how `id` is constructed, e.g. to pick a signing mode.
+    pub fn register(&mut self, id: AlgorithmId, constructor: Constructor) {
+        self.constructors.insert(id, constructor);
+    }
+
+    /// The scheme for `id`, with signing randomness seeded from the OS.
+    pub fn get(&self, id: AlgorithmId) -> Result<Box<dyn DynSigningScheme>, Error> {
+        self.get_with_rng(id, &mut OsRng)
+    }
+
+    /// Like `get`, but seeds the scheme's signing randomness from `rng`, so a seeded `rng`
+    /// makes signing reproducible.
+    pub fn get_with_rng(
+        &self,
+        id: AlgorithmId,
+        rng: &mut dyn CryptoRngCore,
+    ) -> Result<Box<dyn DynSigningScheme>, Error> {
+        self.constructors
+            .get(&id)
+            .map(|constructor| constructor(rng))
+            .ok_or(Error::UnknownAlgorithm)
+    }
+}
+
+impl Default for Registry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
+
+    use super::*;
+    use crate::{kat::on_large_stack, mldsa::SigningMode};
+
+    #[test]
+    fn ids_round_trip_through_codes_and_names() {
+        for id in AlgorithmId::ALL {
+            assert_eq!(AlgorithmId::try_from(id.code()), Ok(id));
+            assert_eq!(id.name().parse(), Ok(id));
+            assert_eq!(id.to_string(), id.name());
+        }
+
+        let codes: HashSet<_> = AlgorithmId::ALL.iter().map(|id| id.code()).collect();
+        let names: HashSet<_> = AlgorithmId::ALL.iter().map(|id| id.name()).collect();
+        assert_eq!(codes.len(), AlgorithmId::ALL.len());
+        assert_eq!(names.len(), AlgorithmId::ALL.len());
+
+        assert_eq!(AlgorithmId::try_from(0), Err(Error::UnknownAlgorithm));
+        assert_eq!(
+            "falcon-256".parse::<AlgorithmId>(),
+            Err(Error::UnknownAlgorithm)
+        );
+    }
+
+    #[test]
+    fn every_algorithm_round_trips() {
+        on_large_stack(|| {
+            let registry = Registry::new();
+            let mut rng = ChaCha20Rng::seed_from_u64(0);
+            let m = b"registry test message";
+
+            // Larger RSA moduli, Falcon-1024 and most SLH-DSA sets take from tens of seconds to
+            // minutes in debug builds; their own tests cover them, and the rest run the same code
+            let slow = |id: &AlgorithmId| {
+                matches!(
+                    id,
+                    AlgorithmId::RsaPss3072 | AlgorithmId::RsaPss4096 | AlgorithmId::Falcon1024
+                ) || id.name().starts_with("slh-dsa") && !id.name().ends_with("128f")
+            };
+            let ids = AlgorithmId::ALL.into_iter().filter(|id| !slow(id));
+
+            for id in ids {
+                let mut scheme = registry.get_with_rng(id, &mut rng).unwrap();
+                let (sk, pk) = scheme.keygen_with_rng(&mut rng).unwrap();
+                assert_eq!(scheme.public_key(&sk).unwrap(), pk, "{id}");
+
+                let t = scheme.sign(&sk, m).unwrap();
+                assert_eq!(scheme.verify(&pk, m, &t), Ok(()), "{id}");
+                assert!(scheme.verify(&pk, b"another message", &t).is_err(), "{id}");
+
+                let info = scheme.info();
+                assert_eq!(pk.len(), info.public_key_size, "{id}");
+                assert!(t.len() <= info.max_signature_size, "{id}");
+
+                // A signing key survives being saved and loaded by a caller that only has the id
+                let encoded = scheme.signing_key_to_bytes(&sk).unwrap();
+                assert_eq!(encoded.len(), info.secret_key_size, "{id}");
+                let sk = scheme.signing_key_from_bytes(&encoded).unwrap();
+                assert_eq!(scheme.public_key(&sk).unwrap(), pk, "{id}");
+                assert_eq!(scheme.signing_key_to_bytes(&sk).unwrap(), encoded, "{id}");
+                let t = scheme.sign(&sk, m).unwrap();
+                assert_eq!(scheme.verify(&pk, m, &t), Ok(()), "{id}");
+            }
+        });
+    }
+
+    #[test]
+    fn keys_of_other_algorithms_are_refused() {
+        let registry = Registry::new();
+        let mut rng = ChaCha20Rng::seed_from_u64(1);
+
+        let mut eddsa = registry.get(AlgorithmId::Ed25519).unwrap();
+        let mut falcon = registry.get(AlgorithmId::Falcon512).unwrap();
+        let (sk, _) = eddsa.keygen_with_rng(&mut rng).unwrap();
+        assert_eq!(falcon.sign(&sk, b"m"), Err(Error::KeyTypeMismatch));
+        assert_eq!(falcon.public_key(&sk), Err(Error::KeyTypeMismatch));
+        assert!(matches!(
+            falcon.signing_key_to_bytes(&sk),
+            Err(Error::KeyTypeMismatch)
+        ));
+
+        // Encoded keys are checked against the scheme that loads them
+        let encoded = eddsa.signing_key_to_bytes(&sk).unwrap();
+        assert!(matches!(
+            falcon.signing_key_from_bytes(&encoded),
+            Err(Error::Decode { .. })
+        ));
+        assert!(matches!(
+            eddsa.signing_key_from_bytes(&encoded[1..]),
+            Err(Error::Decode { .. })
+        ));
+
+        // Same family, other parameter set
+        let mut mldsa44 = registry.get(AlgorithmId::MlDsa44).unwrap();
+        let mut mldsa65 = registry.get(AlgorithmId::MlDsa65).unwrap();
+        let (sk, _) = mldsa44.keygen_with_rng(&mut rng).unwrap();
+        assert_eq!(mldsa65.sign(&sk, b"m"), Err(Error::KeyTypeMismatch));
+        assert!(mldsa44.sign(&sk, b"m").is_ok());
+    }
+
+    #[test]
+    fn seeded_schemes_sign_reproducibly() {
+        let registry = Registry::new();
+        let (sk, _) = registry
+            .get(AlgorithmId::Falcon512)
+            .unwrap()
+            .keygen_with_rng(&mut ChaCha20Rng::seed_from_u64(2))
+            .unwrap();
+
+        let sign = |seed| {
+            let mut rng = ChaCha20Rng::seed_from_u64(seed);
+            let mut falcon = registry
+                .get_with_rng(AlgorithmId::Falcon512, &mut rng)
+                .unwrap();
+            falcon.sign(&sk, b"m").unwrap()
+        };
+
+        assert_eq!(sign(0), sign(0));
+        assert_ne!(sign(0), sign(1));
+    }
+
+    #[test]
+    fn constructors_can_be_replaced() {
+        let mut registry = Registry {
+            constructors: HashMap::new(),
+        };
+        assert!(matches!(
+            registry.get(AlgorithmId::MlDsa65),
+            Err(Error::UnknownAlgorithm)
+        ));
+
+        registry.register(AlgorithmId::MlDsa65, |rng| {
+            Box::new(MlDsa65::from_rng(rng).with_mode(SigningMode::Deterministic))
+        });
+
+        let mut mldsa = registry.get(AlgorithmId::MlDsa65).unwrap();
+        let (sk, _) = mldsa.keygen().unwrap();
+        assert_eq!(mldsa.sign(&sk, b"m"), mldsa.sign(&sk, b"m"));
+    }
+}
@@ -0,0 +1,251 @@
+use std::fmt;
+
+use rand::rngs::OsRng;
+use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
+use rand_core::CryptoRngCore;
+use rsa::{
+    pkcs1::{DecodeRsaPrivateKey, DecodeRsaPublicKey, EncodeRsaPrivateKey, EncodeRsaPublicKey},
+    pss::{BlindedSigningKey, VerifyingKey as PssVerifyingKey},
+    signature::{Keypair, RandomizedSigner, SignatureEncoding, Verifier},
+    traits::PublicKeyParts,
+    RsaPrivateKey, RsaPublicKey,
+};
+use sha2::Sha256;
+use zeroize::Zeroizing;
+
+use crate::{
+    signing_scheme::{SchemeInfo, SigningScheme, ToBytes},
+    Error,
+};
+
+pub type RsaPss2048 = RsaPss<2048>;
+pub type RsaPss3072 = RsaPss<3072>;
+pub type RsaPss4096 = RsaPss<4096>;
+
+/// RSASSA-PSS with SHA-256, MGF1 and a 32-byte salt, over a `BITS`-bit modulus with e = 65537.
+/// Public keys are PKCS#1 `RSAPublicKey` DER, as carried in certificates.
+pub struct RsaPss<const BITS: usize> {
+    rng: ChaCha20Rng,
+}
+
+impl<const BITS: usize> RsaPss<BITS> {
+    /// Salts drawn from a PRNG seeded by the OS.
+    pub fn new() -> Self {
+        Self::from_rng(&mut OsRng)
+    }
+
+    /// Like `new`, but seeds the salt PRNG from `rng`.
+    pub fn from_rng(rng: &mut dyn CryptoRngCore) -> Self {
+        let mut seed = Zeroizing::new([0u8; 32]);
+        rng.fill_bytes(&mut *seed);
+
+        Self {
+            rng: ChaCha20Rng::from_seed(*seed),
+        }
+    }
+}
+
+impl<const BITS: usize> Default for RsaPss<BITS> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub struct SigningKey<const BITS: usize>(BlindedSigningKey<Sha256>);
+
+impl<const BITS: usize> fmt::Debug for SigningKey<BITS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SigningKey").finish_non_exhaustive()
+    }
+}
+
+/// Encoded as PKCS#1 `RSAPrivateKey` DER.
+impl<const BITS: usize> ToBytes for SigningKey<BITS> {
+    fn to_bytes(&self) -> Vec<u8> {
+        // Encoding a well-formed private key cannot fail
+        let der = self.0.as_ref().to_pkcs1_der().unwrap();
+        der.as_bytes().to_vec()
+    }
+}
+
+impl<const BITS: usize> TryFrom<&[u8]> for SigningKey<BITS> {
+    type Error = Error;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        let sk = RsaPrivateKey::from_pkcs1_der(value).map_err(|_| Error::RsaFormat)?;
+
+        if sk.size() * 8 != BITS || sk.validate().is_err() {
+            return Err(Error::RsaFormat);
+        }
+
+        Ok(SigningKey(BlindedSigningKey::new(sk)))
+    }
+}
+
+pub struct VerifyingKey<const BITS: usize>(PssVerifyingKey<Sha256>);
+
+impl<const BITS: usize> ToBytes for VerifyingKey<BITS> {
+    fn to_bytes(&self) -> Vec<u8> {
+        // Encoding a well-formed public key cannot fail
+        self.0.as_ref().to_pkcs1_der().unwrap().into_vec()
+    }
+}
+
+impl<const BITS: usize> TryFrom<&[u8]> for VerifyingKey<BITS> {
+    type Error = Error;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        let pk = RsaPublicKey::from_pkcs1_der(value).map_err(|_| Error::RsaFormat)?;
+
+        if pk.size() * 8 != BITS {
+            return Err(Error::RsaFormat);
+        }
+
+        Ok(VerifyingKey(PssVerifyingKey::new(pk)))
+    }
+}
+
+pub struct Signature<const BITS: usize>(rsa::pss::Signature);
+
+impl<const BITS: usize> ToBytes for Signature<BITS> {
+    fn to_bytes(&self) -> Vec<u8> {
+        self.0.to_vec()
+    }
+}
+
+impl<const BITS: usize> TryFrom<&[u8]> for Signature<BITS> {
+    type Error = Error;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        if value.len() * 8 != BITS {
+            return Err(Error::RsaFormat);
+        }
+
+        rsa::pss::Signature::try_from(value)
+            .map(Signature)
+            .map_err(|_| Error::RsaFormat)
+    }
+}
+
+impl<const BITS: usize> SigningScheme for RsaPss<BITS> {
+    type SigningKey = SigningKey<BITS>;
+
+    type VerifyingKey = VerifyingKey<BITS>;
+
+    type Signature = Signature<BITS>;
+
+    /// The secret key size is that of its PKCS#1 DER encoding, which varies by a few bytes.
+    fn info(&self) -> SchemeInfo {
+        let k = BITS / 8;
+
+        SchemeInfo {
+            public_key_size: k + 14,
+            secret_key_size: k * 9 / 2 + 39,
+            max_signature_size: k,
+            typical_signature_size: k,
+            nist_level: None,
+        }
+    }
+
+    fn keygen(&mut self) -> Result<(Self::SigningKey, Self::VerifyingKey), Error> {
+        self.keygen_with_rng(&mut OsRng)
+    }
+
+    fn keygen_with_rng(
+        &mut self,
+        rng: &mut dyn CryptoRngCore,
+    ) -> Result<(Self::SigningKey, Self::VerifyingKey), Error> {
+        let sk = RsaPrivateKey::new(rng, BITS).map_err(|_| Error::RsaKeygen)?;
+        let sk = BlindedSigningKey::new(sk);
+        let pk = sk.verifying_key();
+        Ok((SigningKey(sk), VerifyingKey(pk)))
+    }
+
+    fn public_key(&self, sk: &Self::SigningKey) -> Result<Self::VerifyingKey, Error> {
+        Ok(VerifyingKey(sk.0.verifying_key()))
+    }
+
+    fn sign(&mut self, sk: &Self::SigningKey, m: &[u8]) -> Result<Self::Signature, Error> {
+        sk.0.try_sign_with_rng(&mut self.rng, m)
+            .map(Signature)
+            .map_err(|_| Error::RsaSign)
+    }
+
+    fn verify(
+        &mut self,
+        pk: &Self::VerifyingKey,
+        m: &[u8],
+        t: &Self::Signature,
+    ) -> Result<(), Error> {
+        pk.0.verify(m, &t.0).map_err(|_| Error::RsaVerify)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kat;
+
+    /// Decodes and verifies, as a caller holding only bytes would.
+    fn verify_bytes<const BITS: usize>(pk: &[u8], m: &[u8], t: &[u8]) -> Result<(), Error> {
+        let pk = VerifyingKey::<BITS>::try_from(pk)?;
+        let t = Signature::<BITS>::try_from(t)?;
+        RsaPss::<BITS>::new().verify(&pk, m, &t)
+    }
+
+    #[test]
+    fn wycheproof_signature_verification() {
+        for group in kat::acvp_groups("rsa_pss_2048_sha256_mgf1_32_test.json") {
+            let pk = kat::hex(&group["publicKeyAsn"]);
+
+            for test in group["tests"].as_array().unwrap() {
+                let res =
+                    verify_bytes::<2048>(&pk, &kat::hex(&test["msg"]), &kat::hex(&test["sig"]));
+                assert_eq!(
+                    res.is_ok(),
+                    test["result"] == "valid",
+                    "tcId {}",
+                    test["tcId"]
+                );
+            }
+
+            // The key is only good for the modulus size it was made for
+            assert_eq!(
+                VerifyingKey::<3072>::try_from(&pk[..]).err(),
+                Some(Error::RsaFormat)
+            );
+        }
+    }
+
+    #[test]
+    fn round_trips() {
+        let mut rng = ChaCha20Rng::seed_from_u64(0);
+        let mut rsa = RsaPss2048::from_rng(&mut rng);
+        let info = rsa.info();
+        let (sk, pk) = rsa.keygen_with_rng(&mut rng).unwrap();
+        let pk = pk.to_bytes();
+        let m = b"test message";
+
+        assert_eq!(pk.len(), info.public_key_size);
+        assert_eq!(rsa.public_key(&sk).unwrap().to_bytes(), pk);
+
+        let t = rsa.sign(&sk, m).unwrap().to_bytes();
+        assert_eq!(t.len(), info.max_signature_size);
+        assert_eq!(verify_bytes::<2048>(&pk, m, &t), Ok(()));
+
+        // Salts are random, so signatures differ
+        assert_ne!(rsa.sign(&sk, m).unwrap().to_bytes(), t);
+
+        assert_eq!(
+            verify_bytes::<2048>(&pk, b"another message", &t),
+            Err(Error::RsaVerify)
+        );
+        for i in [0, t.len() / 2, t.len() - 1] {
+            let mut mutant = t.clone();
+            mutant[i] ^= 1;
+            assert_eq!(verify_bytes::<2048>(&pk, m, &mutant), Err(Error::RsaVerify));
+        }
+        assert_eq!(verify_bytes::<2048>(&pk, m, &t[1..]), Err(Error::RsaFormat));
+        assert_eq!(verify_bytes::<2048>(&pk[1..], m, &t), Err(Error::RsaFormat));
+    }
+}
@@ -1,13 +1,128 @@
+use std::any::Any;
+
+use rand_core::CryptoRngCore;
+use zeroize::Zeroizing;
+
+use crate::Error;
+
 pub trait ToBytes {
     fn to_bytes(&self) -> Vec<u8>;
 }
 
+/// Encoded sizes in bytes, and the strength a scheme claims.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SchemeInfo {
+    pub public_key_size: usize,
+    pub secret_key_size: usize,
+    pub max_signature_size: usize,
+    /// Mean size for variable-length signatures, otherwise the same as the maximum.
+    pub typical_signature_size: usize,
+    /// NIST PQC security category, or `None` for schemes a quantum computer breaks.
+    pub nist_level: Option<u8>,
+}
+
 pub trait SigningScheme {
-    type SigningKey;
-    type VerifyingKey: ToBytes + From<Vec<u8>>;
-    type Signature: ToBytes + From<Vec<u8>>;
+    /// `to_bytes` gives the secret key itself, so callers should wipe it when done.
+    type SigningKey: ToBytes + for<'a> TryFrom<&'a [u8], Error = Error>;
+    type VerifyingKey: ToBytes + for<'a> TryFrom<&'a [u8], Error = Error>;
+    type Signature: ToBytes + for<'a> TryFrom<&'a [u8], Error = Error>;
+
+    fn info(&self) -> SchemeInfo;
+    fn keygen(&mut self) -> Result<(Self::SigningKey, Self::VerifyingKey), Error>;
+    /// Like `keygen`, but draws all randomness from `rng`.
+    fn keygen_with_rng(
+        &mut self,
+        rng: &mut dyn CryptoRngCore,
+    ) -> Result<(Self::SigningKey, Self::VerifyingKey), Error>;
+    /// Recomputes the verifying key belonging to `sk`.
+    fn public_key(&self, sk: &Self::SigningKey) -> Result<Self::VerifyingKey, Error>;
+    fn sign(&mut self, sk: &Self::SigningKey, m: &[u8]) -> Result<Self::Signature, Error>;
+    fn verify(
+        &mut self,
+        pk: &Self::VerifyingKey,
+        m: &[u8],
+        t: &Self::Signature,
+    ) -> Result<(), Error>;
+    /// Verifies each `(pk, m, t)`, returning one result per item in the same order.
+    fn verify_batch(
+        &mut self,
+        items: &[(&Self::VerifyingKey, &[u8], &Self::Signature)],
+    ) -> Vec<Result<(), Error>> {
+        items
+            .iter()
+            .map(|(pk, m, t)| self.verify(pk, m, t))
+            .collect()
+    }
+}
+
+/// A signing key of whichever scheme made it, for use through `DynSigningScheme`.
+pub struct DynSigningKey(Box<dyn Any>);
+
+/// Object-safe view of a `SigningScheme`, with keys and signatures passed encoded. Implemented
+/// for every `SigningScheme`; `registry::Registry` picks one by `AlgorithmId`.
+pub trait DynSigningScheme {
+    fn info(&self) -> SchemeInfo;
+    fn keygen(&mut self) -> Result<(DynSigningKey, Vec<u8>), Error>;
+    fn keygen_with_rng(
+        &mut self,
+        rng: &mut dyn CryptoRngCore,
+    ) -> Result<(DynSigningKey, Vec<u8>), Error>;
+    fn public_key(&self, sk: &DynSigningKey) -> Result<Vec<u8>, Error>;
+    fn signing_key_from_bytes(&self, sk: &[u8]) -> Result<DynSigningKey, Error>;
+    /// Fails with `Error::KeyTypeMismatch` if `sk` was made by a different scheme.
+    fn signing_key_to_bytes(&self, sk: &DynSigningKey) -> Result<Zeroizing<Vec<u8>>, Error>;
+    /// Fails with `Error::KeyTypeMismatch` if `sk` was made by a different scheme.
+    fn sign(&mut self, sk: &DynSigningKey, m: &[u8]) -> Result<Vec<u8>, Error>;
+    fn verify(&mut self, pk: &[u8], m: &[u8], t: &[u8]) -> Result<(), Error>;
+}
+
+fn downcast<S: SigningScheme>(sk: &DynSigningKey) -> Result<&S::SigningKey, Error>
+where
+    S::SigningKey: 'static,
+{
+    sk.0.downcast_ref().ok_or(Error::KeyTypeMismatch)
+}
+
+impl<S: SigningScheme> DynSigningScheme for S
+where
+    S::SigningKey: 'static,
+{
+    fn info(&self) -> SchemeInfo {
+        SigningScheme::info(self)
+    }
+
+    fn keygen(&mut self) -> Result<(DynSigningKey, Vec<u8>), Error> {
+        let (sk, pk) = SigningScheme::keygen(self)?;
+        Ok((DynSigningKey(Box::new(sk)), pk.to_bytes()))
+    }
+
+    fn keygen_with_rng(
+        &mut self,
+        rng: &mut dyn CryptoRngCore,
+    ) -> Result<(DynSigningKey, Vec<u8>), Error> {
+        let (sk, pk) = SigningScheme::keygen_with_rng(self, rng)?;
+        Ok((DynSigningKey(Box::new(sk)), pk.to_bytes()))
+    }
+
+    fn public_key(&self, sk: &DynSigningKey) -> Result<Vec<u8>, Error> {
+        Ok(SigningScheme::public_key(self, downcast::<S>(sk)?)?.to_bytes())
+    }
+
+    fn signing_key_from_bytes(&self, sk: &[u8]) -> Result<DynSigningKey, Error> {
+        Ok(DynSigningKey(Box::new(S::SigningKey::try_from(sk)?)))
+    }
+
+    fn signing_key_to_bytes(&self, sk: &DynSigningKey) -> Result<Zeroizing<Vec<u8>>, Error> {
+        Ok(Zeroizing::new(downcast::<S>(sk)?.to_bytes()))
+    }
+
+    fn sign(&mut self, sk: &DynSigningKey, m: &[u8]) -> Result<Vec<u8>, Error> {
+        Ok(SigningScheme::sign(self, downcast::<S>(sk)?, m)?.to_bytes())
+    }
 
-    fn keygen(&mut self) -> (Self::SigningKey, Self::VerifyingKey);
-    fn sign(&mut self, sk: &Self::SigningKey, m: &[u8]) -> Self::Signature;
-    fn verify(&mut self, pk: &Self::VerifyingKey, m: &[u8], t: &Self::Signature) -> bool;
+    fn verify(&mut self, pk: &[u8], m: &[u8], t: &[u8]) -> Result<(), Error> {
+        let pk = S::VerifyingKey::try_from(pk)?;
+        let t = S::Signature::try_from(t)?;
+        SigningScheme::verify(self, &pk, m, &t)
+    }
 }
@@ -0,0 +1,433 @@
+use std::{fmt, marker::PhantomData};
+
+use rand::rngs::OsRng;
+use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
+use rand_core::CryptoRngCore;
+use slh_dsa::{
+    signature::{Keypair, RandomizedSigner, Verifier},
+    ParameterSet,
+};
+use zeroize::Zeroizing;
+
+use crate::{
+    registry::AlgorithmId,
+    signing_scheme::{SchemeInfo, SigningScheme, ToBytes},
+    Error,
+};
+
+pub type SlhDsaSha2_128s = SlhDsa<slh_dsa::Sha2_128s>;
+pub type SlhDsaSha2_128f = SlhDsa<slh_dsa::Sha2_128f>;
+pub type SlhDsaSha2_192s = SlhDsa<slh_dsa::Sha2_192s>;
+pub type SlhDsaSha2_192f = SlhDsa<slh_dsa::Sha2_192f>;
+pub type SlhDsaSha2_256s = SlhDsa<slh_dsa::Sha2_256s>;
+pub type SlhDsaSha2_256f = SlhDsa<slh_dsa::Sha2_256f>;
+pub type SlhDsaShake128s = SlhDsa<slh_dsa::Shake128s>;
+pub type SlhDsaShake128f = SlhDsa<slh_dsa::Shake128f>;
+pub type SlhDsaShake192s = SlhDsa<slh_dsa::Shake192s>;
+pub type SlhDsaShake192f = SlhDsa<slh_dsa::Shake192f>;
+pub type SlhDsaShake256s = SlhDsa<slh_dsa::Shake256s>;
+pub type SlhDsaShake256f = SlhDsa<slh_dsa::Shake256f>;
+
+/// Sizes and security category of a parameter set (FIPS 205, Table 2).
+pub trait Params: ParameterSet {
+    /// Security parameter: public keys are `2n` bytes and signing keys `4n`.
+    const N: usize;
+    const SIG_LEN: usize;
+    const LEVEL: u8;
+    const ALGORITHM: AlgorithmId;
+}
+
+impl Params for slh_dsa::Sha2_128s {
+    const N: usize = 16;
+    const SIG_LEN: usize = 7856;
+    const LEVEL: u8 = 1;
+    const ALGORITHM: AlgorithmId = AlgorithmId::SlhDsaSha2_128s;
+}
+
+impl Params for slh_dsa::Sha2_128f {
+    const N: usize = 16;
+    const SIG_LEN: usize = 17088;
+    const LEVEL: u8 = 1;
+    const ALGORITHM: AlgorithmId = AlgorithmId::SlhDsaSha2_128f;
+}
+
+impl Params for slh_dsa::Sha2_192s {
+    const N: usize = 24;
+    const SIG_LEN: usize = 16224;
+    const LEVEL: u8 = 3;
+    const ALGORITHM: AlgorithmId = AlgorithmId::SlhDsaSha2_192s;
+}
+
+impl Params for slh_dsa::Sha2_192f {
+    const N: usize = 24;
+    const SIG_LEN: usize = 35664;
+    const LEVEL: u8 = 3;
+    const ALGORITHM: AlgorithmId = AlgorithmId::SlhDsaSha2_192f;
+}
+
+impl Params for slh_dsa::Sha2_256s {
+    const N: usize = 32;
+    const SIG_LEN: usize = 29792;
+    const LEVEL: u8 = 5;
+    const ALGORITHM: AlgorithmId = AlgorithmId::SlhDsaSha2_256s;
+}
+
+impl Params for slh_dsa::Sha2_256f {
+    const N: usize = 32;
+    const SIG_LEN: usize = 49856;
+    const LEVEL: u8 = 5;
+    const ALGORITHM: AlgorithmId = AlgorithmId::SlhDsaSha2_256f;
+}
+
+impl Params for slh_dsa::Shake128s {
+    const N: usize = 16;
+    const SIG_LEN: usize = 7856;
+    const LEVEL: u8 = 1;
+    const ALGORITHM: AlgorithmId = AlgorithmId::SlhDsaShake128s;
+}
+
+impl Params for slh_dsa::Shake128f {
+    const N: usize = 16;
+    const SIG_LEN: usize = 17088;
+    const LEVEL: u8 = 1;
+    const ALGORITHM: AlgorithmId = AlgorithmId::SlhDsaShake128f;
+}
+
+impl Params for slh_dsa::Shake192s {
+    const N: usize = 24;
+    const SIG_LEN: usize = 16224;
+    const LEVEL: u8 = 3;
+    const ALGORITHM: AlgorithmId = AlgorithmId::SlhDsaShake192s;
+}
+
+impl Params for slh_dsa::Shake192f {
+    const N: usize = 24;
+    const SIG_LEN: usize = 35664;
+    const LEVEL: u8 = 3;
+    const ALGORITHM: AlgorithmId = AlgorithmId::SlhDsaShake192f;
+}
+
+impl Params for slh_dsa::Shake256s {
+    const N: usize = 32;
+    const SIG_LEN: usize = 29792;
+    const LEVEL: u8 = 5;
+    const ALGORITHM: AlgorithmId = AlgorithmId::SlhDsaShake256s;
+}
+
+impl Params for slh_dsa::Shake256f {
+    const N: usize = 32;
+    const SIG_LEN: usize = 49856;
+    const LEVEL: u8 = 5;
+    const ALGORITHM: AlgorithmId = AlgorithmId::SlhDsaShake256f;
+}
+
+/// SLH-DSA (FIPS 205) with hedged signing and an empty context string. The "s" parameter sets
+/// trade slower signing for smaller signatures than the "f" ones.
+pub struct SlhDsa<P> {
+    rng: ChaCha20Rng,
+    params: PhantomData<fn() -> P>,
+}
+
+impl<P: Params> SlhDsa<P> {
+    /// Randomness seeded from the OS.
+    pub fn new() -> Self {
+        Self::from_rng(&mut OsRng)
+    }
+
+    /// Like `new`, but seeds the hedging RNG from `rng`.
+    pub fn from_rng(rng: &mut dyn CryptoRngCore) -> Self {
+        let mut seed = Zeroizing::new([0u8; 32]);
+        rng.fill_bytes(&mut *seed);
+
+        Self {
+            rng: ChaCha20Rng::from_seed(*seed),
+            params: PhantomData,
+        }
+    }
+}
+
+impl<P: Params> Default for SlhDsa<P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub struct SigningKey<P: Params>(slh_dsa::SigningKey<P>);
+
+impl<P: Params> fmt::Debug for SigningKey<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SigningKey").finish_non_exhaustive()
+    }
+}
+
+/// Encoded as FIPS 205's `SK.seed || SK.prf || PK.seed || PK.root`.
+impl<P: Params> ToBytes for SigningKey<P> {
+    fn to_bytes(&self) -> Vec<u8> {
+        self.0.to_bytes().to_vec()
+    }
+}
+
+impl<P: Params> TryFrom<&[u8]> for SigningKey<P> {
+    type Error = Error;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        slh_dsa::SigningKey::try_from(value)
+            .map(SigningKey)
+            .map_err(|_| Error::SlhDsaFormat)
+    }
+}
+
+pub struct VerifyingKey<P: Params>(slh_dsa::VerifyingKey<P>);
+
+impl<P: Params> ToBytes for VerifyingKey<P> {
+    fn to_bytes(&self) -> Vec<u8> {
+        self.0.to_bytes().to_vec()
+    }
+}
+
+impl<P: Params> TryFrom<&[u8]> for VerifyingKey<P> {
+    type Error = Error;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        slh_dsa::VerifyingKey::try_from(value)
+            .map(VerifyingKey)
+            .map_err(|_| Error::SlhDsaFormat)
+    }
+}
+
+pub struct Signature<P: Params>(slh_dsa::Signature<P>);
+
+impl<P: Params> ToBytes for Signature<P> {
+    fn to_bytes(&self) -> Vec<u8> {
+        self.0.to_vec()
+    }
+}
+
+impl<P: Params> TryFrom<&[u8]> for Signature<P> {
+    type Error = Error;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        slh_dsa::Signature::try_from(value)
+            .map(Signature)
+            .map_err(|_| Error::SlhDsaFormat)
+    }
+}
+
+impl<P: Params> SigningScheme for SlhDsa<P> {
+    type SigningKey = SigningKey<P>;
+
+    type VerifyingKey = VerifyingKey<P>;
+
+    type Signature = Signature<P>;
+
+    fn info(&self) -> SchemeInfo {
+        SchemeInfo {
+            public_key_size: 2 * P::N,
+            secret_key_size: 4 * P::N,
+            max_signature_size: P::SIG_LEN,
+            typical_signature_size: P::SIG_LEN,
+            nist_level: Some(P::LEVEL),
+        }
+    }
+
+    fn keygen(&mut self) -> Result<(Self::SigningKey, Self::VerifyingKey), Error> {
+        self.keygen_with_rng(&mut OsRng)
+    }
+
+    fn keygen_with_rng(
+        &mut self,
+        mut rng: &mut dyn CryptoRngCore,
+    ) -> Result<(Self::SigningKey, Self::VerifyingKey), Error> {
+        let sk = slh_dsa::SigningKey::new(&mut rng);
+        let pk = sk.verifying_key();
+        Ok((SigningKey(sk), VerifyingKey(pk)))
+    }
+
+    fn public_key(&self, sk: &Self::SigningKey) -> Result<Self::VerifyingKey, Error> {
+        Ok(VerifyingKey(sk.0.verifying_key()))
+    }
+
+    fn sign(&mut self, sk: &Self::SigningKey, m: &[u8]) -> Result<Self::Signature, Error> {
+        Ok(Signature(sk.0.sign_with_rng(&mut self.rng, m)))
+    }
+
+    fn verify(
+        &mut self,
+        pk: &Self::VerifyingKey,
+        m: &[u8],
+        t: &Self::Signature,
+    ) -> Result<(), Error> {
+        pk.0.verify(m, &t.0).map_err(|_| Error::SlhDsaVerify)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::Value;
+
+    use super::*;
+    use crate::kat::{self, Replay};
+
+    const M: &[u8] = b"SLH-DSA round trip";
+
+    fn round_trip<P: Params>() {
+        let mut rng = ChaCha20Rng::seed_from_u64(0);
+        let mut scheme = SlhDsa::<P>::from_rng(&mut rng);
+        let info = scheme.info();
+        let (sk, pk) = scheme.keygen_with_rng(&mut rng).unwrap();
+        let pk = pk.to_bytes();
+        assert_eq!(pk.len(), info.public_key_size);
+        assert_eq!(sk.0.to_bytes().len(), info.secret_key_size);
+        assert_eq!(scheme.public_key(&sk).unwrap().to_bytes(), pk);
+
+        let t = scheme.sign(&sk, M).unwrap();
+        assert_eq!(t.to_bytes().len(), info.max_signature_size);
+        assert_ne!(scheme.sign(&sk, M).unwrap().to_bytes(), t.to_bytes());
+
+        let pk = VerifyingKey::<P>::try_from(&pk[..]).unwrap();
+        let decoded = Signature::<P>::try_from(&t.to_bytes()[..]).unwrap();
+        assert_eq!(scheme.verify(&pk, M, &decoded), Ok(()));
+        assert_eq!(
+            scheme.verify(&pk, b"another message", &decoded),
+            Err(Error::SlhDsaVerify)
+        );
+
+        // Signing is the external interface: slh_sign_internal over `0 || len(ctx) || ctx || M`
+        assert!(pk.0.slh_verify_internal(&[&[0, 0], M], &t.0).is_ok());
+
+        let mut mutant = t.to_bytes();
+        mutant[info.max_signature_size / 2] ^= 1;
+        let mutant = Signature::<P>::try_from(&mutant[..]).unwrap();
+        assert_eq!(scheme.verify(&pk, M, &mutant), Err(Error::SlhDsaVerify));
+    }
+
+    #[test]
+    fn round_trips() {
+        round_trip::<slh_dsa::Sha2_128f>();
+        round_trip::<slh_dsa::Shake128f>();
+    }
+
+    /// The sizes in `Params` against the ones slh-dsa decodes.
+    fn sizes<P: Params>() {
+        let info = SlhDsa::<P>::new().info();
+        let zeros = vec![0; info.max_signature_size + 1];
+
+        assert!(slh_dsa::SigningKey::<P>::try_from(&zeros[..info.secret_key_size]).is_ok());
+        assert!(slh_dsa::SigningKey::<P>::try_from(&zeros[..info.secret_key_size - 1]).is_err());
+        assert!(VerifyingKey::<P>::try_from(&zeros[..info.public_key_size]).is_ok());
+        assert_eq!(
+            VerifyingKey::<P>::try_from(&zeros[..info.public_key_size + 1]).map(|_| ()),
+            Err(Error::SlhDsaFormat)
+        );
+        assert!(Signature::<P>::try_from(&zeros[..info.max_signature_size]).is_ok());
+        for len in [info.max_signature_size - 1, info.max_signature_size + 1] {
+            assert_eq!(
+                Signature::<P>::try_from(&zeros[..len]).map(|_| ()),
+                Err(Error::SlhDsaFormat),
+                "{}",
+                P::NAME
+            );
+        }
+    }
+
+    #[test]
+    fn sizes_match_the_parameter_sets() {
+        sizes::<slh_dsa::Sha2_128s>();
+        sizes::<slh_dsa::Sha2_128f>();
+        sizes::<slh_dsa::Sha2_192s>();
+        sizes::<slh_dsa::Sha2_192f>();
+        sizes::<slh_dsa::Sha2_256s>();
+        sizes::<slh_dsa::Sha2_256f>();
+        sizes::<slh_dsa::Shake128s>();
+        sizes::<slh_dsa::Shake128f>();
+        sizes::<slh_dsa::Shake192s>();
+        sizes::<slh_dsa::Shake192f>();
+        sizes::<slh_dsa::Shake256s>();
+        sizes::<slh_dsa::Shake256f>();
+    }
+
+    /// Runs `check::<P>` on each ACVP test group, for the group's parameter set.
+    macro_rules! for_each_group {
+        ($file:expr, $check:ident) => {
+            for group in kat::acvp_groups($file) {
+                match group["parameterSet"].as_str().unwrap() {
+                    "SLH-DSA-SHA2-128s" => $check::<slh_dsa::Sha2_128s>(&group),
+                    "SLH-DSA-SHA2-128f" => $check::<slh_dsa::Sha2_128f>(&group),
+                    "SLH-DSA-SHA2-192s" => $check::<slh_dsa::Sha2_192s>(&group),
+                    "SLH-DSA-SHA2-192f" => $check::<slh_dsa::Sha2_192f>(&group),
+                    "SLH-DSA-SHA2-256s" => $check::<slh_dsa::Sha2_256s>(&group),
+                    "SLH-DSA-SHA2-256f" => $check::<slh_dsa::Sha2_256f>(&group),
+                    "SLH-DSA-SHAKE-128s" => $check::<slh_dsa::Shake128s>(&group),
+                    "SLH-DSA-SHAKE-128f" => $check::<slh_dsa::Shake128f>(&group),
+                    "SLH-DSA-SHAKE-192s" => $check::<slh_dsa::Shake192s>(&group),
+                    "SLH-DSA-SHAKE-192f" => $check::<slh_dsa::Shake192f>(&group),
+                    "SLH-DSA-SHAKE-256s" => $check::<slh_dsa::Shake256s>(&group),
+                    "SLH-DSA-SHAKE-256f" => $check::<slh_dsa::Shake256f>(&group),
+                    other => panic!("unexpected parameter set {other}"),
+                }
+            }
+        };
+    }
+
+    /// Key generation draws `SK.seed`, `SK.prf` and `PK.seed` from the RNG, in that order.
+    fn keygen<P: Params>(group: &Value) {
+        for t in group["tests"].as_array().unwrap() {
+            let seeds = ["skSeed", "skPrf", "pkSeed"].map(|f| kat::hex(&t[f]));
+            let mut rng = Replay::new(&seeds.each_ref().map(Vec::as_slice));
+            let (sk, pk) = SlhDsa::<P>::new().keygen_with_rng(&mut rng).unwrap();
+
+            assert_eq!(pk.to_bytes(), kat::hex(&t["pk"]), "tcId {}", t["tcId"]);
+            assert_eq!(
+                sk.0.to_bytes().to_vec(),
+                kat::hex(&t["sk"]),
+                "tcId {}",
+                t["tcId"]
+            );
+        }
+    }
+
+    fn sig_gen<P: Params>(group: &Value) {
+        for t in group["tests"].as_array().unwrap() {
+            let sk = slh_dsa::SigningKey::<P>::try_from(&kat::hex(&t["sk"])[..]).unwrap();
+            let rand = match group["deterministic"].as_bool().unwrap() {
+                true => None,
+                false => Some(kat::hex(&t["additionalRandomness"])),
+            };
+
+            let t_ = sk.slh_sign_internal(&[&kat::hex(&t["message"])], rand.as_deref());
+            assert_eq!(t_.to_vec(), kat::hex(&t["signature"]), "tcId {}", t["tcId"]);
+        }
+    }
+
+    fn sig_ver<P: Params>(group: &Value) {
+        for t in group["tests"].as_array().unwrap() {
+            let pk = VerifyingKey::<P>::try_from(&kat::hex(&t["pk"])[..]).unwrap();
+            let m = kat::hex(&t["message"]);
+            let valid = Signature::<P>::try_from(&kat::hex(&t["signature"])[..])
+                .is_ok_and(|sig| pk.0.slh_verify_internal(&[&m], &sig.0).is_ok());
+
+            assert_eq!(
+                valid,
+                t["testPassed"].as_bool().unwrap(),
+                "tcId {}: {}",
+                t["tcId"],
+                t["reason"]
+            );
+        }
+    }
+
+    #[test]
+    fn fips205_key_generation() {
+        for_each_group!("SLH-DSA-keyGen-FIPS205.json", keygen);
+    }
+
+    #[test]
+    fn fips205_signature_generation() {
+        for_each_group!("SLH-DSA-sigGen-FIPS205.json", sig_gen);
+    }
+
+    #[test]
+    fn fips205_signature_verification() {
+        for_each_group!("SLH-DSA-sigVer-FIPS205.json", sig_ver);
+    }
+}
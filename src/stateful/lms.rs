@@ -0,0 +1,792 @@
+//! LMS and HSS (RFC 8554) over SHA-256 with 32-byte outputs.
+
+use rand_core::CryptoRngCore;
+use sha2::{Digest, Sha256};
+use zeroize::Zeroizing;
+
+use super::{Index, StatefulSigningScheme};
+use crate::{signing_scheme::ToBytes, Error};
+
+const N: usize = 32;
+const ID_LEN: usize = 16;
+const PUB_LEN: usize = 4 + 4 + ID_LEN + N;
+const MAX_LEVELS: usize = 8;
+
+const D_PBLC: u16 = 0x8080;
+const D_MESG: u16 = 0x8181;
+const D_LEAF: u16 = 0x8282;
+const D_INTR: u16 = 0x8383;
+
+// Private derivations in the style of RFC 8554 Appendix A, with chain indices no OTS uses
+const D_RAND: u16 = 0xfffd;
+const D_CHILD_SEED: u16 = 0xfffe;
+const D_CHILD_ID: u16 = 0xffff;
+
+fn hash(parts: &[&[u8]]) -> [u8; N] {
+    let mut h = Sha256::new();
+    for part in parts {
+        h.update(part);
+    }
+    h.finalize().into()
+}
+
+/// Tree heights up to 15. A tree is built whole and kept in memory, so the taller RFC 8554 types
+/// (H20 and H25) would take 2^21 or 2^26 nodes; stack HSS levels instead.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LmsType {
+    Sha256M32H5 = 5,
+    Sha256M32H10 = 6,
+    Sha256M32H15 = 7,
+}
+
+impl LmsType {
+    fn from_code(code: u32) -> Option<Self> {
+        match code {
+            5 => Some(LmsType::Sha256M32H5),
+            6 => Some(LmsType::Sha256M32H10),
+            7 => Some(LmsType::Sha256M32H15),
+            _ => None,
+        }
+    }
+
+    /// Tree height; the tree holds `2^h` one-time keys.
+    pub fn h(self) -> u32 {
+        match self {
+            LmsType::Sha256M32H5 => 5,
+            LmsType::Sha256M32H10 => 10,
+            LmsType::Sha256M32H15 => 15,
+        }
+    }
+}
+
+/// Winternitz parameter `w`: larger values give smaller but slower signatures.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LmotsType {
+    Sha256N32W1 = 1,
+    Sha256N32W2 = 2,
+    Sha256N32W4 = 3,
+    Sha256N32W8 = 4,
+}
+
+impl LmotsType {
+    fn from_code(code: u32) -> Option<Self> {
+        match code {
+            1 => Some(LmotsType::Sha256N32W1),
+            2 => Some(LmotsType::Sha256N32W2),
+            3 => Some(LmotsType::Sha256N32W4),
+            4 => Some(LmotsType::Sha256N32W8),
+            _ => None,
+        }
+    }
+
+    fn w(self) -> usize {
+        match self {
+            LmotsType::Sha256N32W1 => 1,
+            LmotsType::Sha256N32W2 => 2,
+            LmotsType::Sha256N32W4 => 4,
+            LmotsType::Sha256N32W8 => 8,
+        }
+    }
+
+    fn p(self) -> usize {
+        match self {
+            LmotsType::Sha256N32W1 => 265,
+            LmotsType::Sha256N32W2 => 133,
+            LmotsType::Sha256N32W4 => 67,
+            LmotsType::Sha256N32W8 => 34,
+        }
+    }
+
+    fn ls(self) -> u32 {
+        match self {
+            LmotsType::Sha256N32W1 => 7,
+            LmotsType::Sha256N32W2 => 6,
+            LmotsType::Sha256N32W4 => 4,
+            LmotsType::Sha256N32W8 => 0,
+        }
+    }
+
+    fn max_digit(self) -> usize {
+        (1 << self.w()) - 1
+    }
+
+    /// `Q || Cksm(Q)`, whose first `p` base-`w` digits select how far along each chain to sign.
+    fn digits(self, q: &[u8; N]) -> Vec<usize> {
+        let w = self.w();
+        let coef = |s: &[u8], i: usize| {
+            let shift = 8 - (w * (i % (8 / w)) + w);
+            ((s[i * w / 8] >> shift) as usize) & self.max_digit()
+        };
+
+        let sum: usize = (0..N * 8 / w).map(|i| self.max_digit() - coef(q, i)).sum();
+        let cksm = ((sum as u16) << self.ls()).to_be_bytes();
+
+        let mut qc = q.to_vec();
+        qc.extend(cksm);
+        (0..self.p()).map(|i| coef(&qc, i)).collect()
+    }
+}
+
+/// Walks chain `i` of one-time key `q` from step `from` to step `to`.
+fn chain(id: &[u8], q: u32, i: usize, from: usize, to: usize, mut tmp: [u8; N]) -> [u8; N] {
+    for j in from..to {
+        tmp = hash(&[
+            id,
+            &q.to_be_bytes(),
+            &(i as u16).to_be_bytes(),
+            &[j as u8],
+            &tmp,
+        ]);
+    }
+    tmp
+}
+
+struct Tree {
+    lms: LmsType,
+    ots: LmotsType,
+    id: [u8; ID_LEN],
+    seed: Zeroizing<[u8; N]>,
+    /// Nodes by RFC 8554 number: the root is 1 and the children of `r` are `2r` and `2r + 1`.
+    nodes: Vec<[u8; N]>,
+}
+
+impl Tree {
+    fn new(lms: LmsType, ots: LmotsType, id: [u8; ID_LEN], seed: Zeroizing<[u8; N]>) -> Self {
+        let leaves = 1usize << lms.h();
+        let mut tree = Tree {
+            lms,
+            ots,
+            id,
+            seed,
+            nodes: vec![[0; N]; 2 * leaves],
+        };
+
+        for q in 0..leaves {
+            let r = (leaves + q) as u32;
+            let k = tree.ots_public(q as u32);
+            tree.nodes[leaves + q] = hash(&[&tree.id, &r.to_be_bytes(), &D_LEAF.to_be_bytes(), &k]);
+        }
+
+        for r in (1..leaves).rev() {
+            tree.nodes[r] = hash(&[
+                &tree.id,
+                &(r as u32).to_be_bytes(),
+                &D_INTR.to_be_bytes(),
+                &tree.nodes[2 * r],
+                &tree.nodes[2 * r + 1],
+            ]);
+        }
+
+        tree
+    }
+
+    fn derive(&self, q: u32, tag: u16) -> Zeroizing<[u8; N]> {
+        Zeroizing::new(hash(&[
+            &self.id,
+            &q.to_be_bytes(),
+            &tag.to_be_bytes(),
+            &[0xff],
+            &*self.seed,
+        ]))
+    }
+
+    fn ots_private(&self, q: u32, i: usize) -> Zeroizing<[u8; N]> {
+        self.derive(q, i as u16)
+    }
+
+    fn ots_public(&self, q: u32) -> [u8; N] {
+        let mut h = Sha256::new();
+        h.update(self.id);
+        h.update(q.to_be_bytes());
+        h.update(D_PBLC.to_be_bytes());
+
+        for i in 0..self.ots.p() {
+            let x = self.ots_private(q, i);
+            h.update(chain(&self.id, q, i, 0, self.ots.max_digit(), *x));
+        }
+
+        h.finalize().into()
+    }
+
+    fn public_key(&self) -> Vec<u8> {
+        let mut res = Vec::with_capacity(PUB_LEN);
+        res.extend((self.lms as u32).to_be_bytes());
+        res.extend((self.ots as u32).to_be_bytes());
+        res.extend(self.id);
+        res.extend(self.nodes[1]);
+        res
+    }
+
+    /// The randomizer `C` is derived rather than drawn, so re-signing the same message (as
+    /// happens when a child tree is rebuilt) reproduces the same one-time signature.
+    fn sign(&self, q: u32, m: &[u8]) -> Vec<u8> {
+        self.sign_with(q, &self.derive(q, D_RAND), m)
+    }
+
+    fn sign_with(&self, q: u32, c: &[u8; N], m: &[u8]) -> Vec<u8> {
+        let digest = hash(&[&self.id, &q.to_be_bytes(), &D_MESG.to_be_bytes(), c, m]);
+
+        let mut res = Vec::new();
+        res.extend(q.to_be_bytes());
+        res.extend((self.ots as u32).to_be_bytes());
+        res.extend(c);
+
+        for (i, a) in self.ots.digits(&digest).into_iter().enumerate() {
+            let x = self.ots_private(q, i);
+            res.extend(chain(&self.id, q, i, 0, a, *x));
+        }
+
+        res.extend((self.lms as u32).to_be_bytes());
+
+        let leaves = 1usize << self.lms.h();
+        for i in 0..self.lms.h() {
+            res.extend(self.nodes[((leaves + q as usize) >> i) ^ 1]);
+        }
+
+        res
+    }
+
+    fn child(&self, q: u32, (lms, ots): (LmsType, LmotsType)) -> Tree {
+        let id = self.derive(q, D_CHILD_ID)[..ID_LEN].try_into().unwrap();
+        Tree::new(lms, ots, id, self.derive(q, D_CHILD_SEED))
+    }
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+    field: &'static str,
+}
+
+impl<'a> Reader<'a> {
+    fn error(&self, offset: usize) -> Error {
+        Error::Decode {
+            field: self.field,
+            offset,
+        }
+    }
+
+    fn take(&mut self, n: usize) -> Result<&'a [u8], Error> {
+        let t = self
+            .bytes
+            .get(self.offset..self.offset + n)
+            .ok_or(self.error(self.offset))?;
+        self.offset += n;
+        Ok(t)
+    }
+
+    fn u32(&mut self) -> Result<u32, Error> {
+        Ok(u32::from_be_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn lms_type(&mut self) -> Result<LmsType, Error> {
+        let at = self.offset;
+        LmsType::from_code(self.u32()?).ok_or(self.error(at))
+    }
+
+    fn lmots_type(&mut self) -> Result<LmotsType, Error> {
+        let at = self.offset;
+        LmotsType::from_code(self.u32()?).ok_or(self.error(at))
+    }
+
+    fn finish(&self) -> Result<(), Error> {
+        match self.offset == self.bytes.len() {
+            true => Ok(()),
+            false => Err(self.error(self.offset)),
+        }
+    }
+}
+
+struct LmsPublic<'a> {
+    lms: LmsType,
+    ots: LmotsType,
+    id: &'a [u8],
+    root: &'a [u8],
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn lms_public(&mut self) -> Result<LmsPublic<'a>, Error> {
+        let start = self.offset;
+
+        Ok(LmsPublic {
+            lms: self.lms_type()?,
+            ots: self.lmots_type()?,
+            id: self.take(ID_LEN)?,
+            root: self.take(N)?,
+            bytes: &self.bytes[start..start + PUB_LEN],
+        })
+    }
+
+    fn lms_signature(&mut self) -> Result<LmsSignature<'a>, Error> {
+        let q = self.u32()?;
+        let ots = self.lmots_type()?;
+        let c = self.take(N)?;
+        let y = self.take(ots.p() * N)?;
+        let lms = self.lms_type()?;
+        let path = self.take(lms.h() as usize * N)?;
+
+        Ok(LmsSignature {
+            q,
+            ots,
+            c,
+            y,
+            lms,
+            path,
+        })
+    }
+}
+
+struct LmsSignature<'a> {
+    q: u32,
+    ots: LmotsType,
+    c: &'a [u8],
+    y: &'a [u8],
+    lms: LmsType,
+    path: &'a [u8],
+}
+
+impl LmsPublic<'_> {
+    fn verify(&self, m: &[u8], t: &LmsSignature) -> Result<(), Error> {
+        let leaves = 1u32 << self.lms.h();
+        if t.lms != self.lms || t.ots != self.ots || t.q >= leaves {
+            return Err(Error::LmsVerify);
+        }
+
+        let q = t.q.to_be_bytes();
+        let digest = hash(&[self.id, &q, &D_MESG.to_be_bytes(), t.c, m]);
+
+        let mut h = Sha256::new();
+        h.update(self.id);
+        h.update(q);
+        h.update(D_PBLC.to_be_bytes());
+
+        for (i, a) in self.ots.digits(&digest).into_iter().enumerate() {
+            let y = t.y[i * N..(i + 1) * N].try_into().unwrap();
+            h.update(chain(self.id, t.q, i, a, self.ots.max_digit(), y));
+        }
+
+        let mut r = leaves + t.q;
+        let mut node = hash(&[
+            self.id,
+            &r.to_be_bytes(),
+            &D_LEAF.to_be_bytes(),
+            &h.finalize(),
+        ]);
+
+        for sibling in t.path.chunks_exact(N) {
+            let (left, right) = match r % 2 {
+                0 => (&node[..], sibling),
+                _ => (sibling, &node[..]),
+            };
+            r /= 2;
+            node = hash(&[
+                self.id,
+                &r.to_be_bytes(),
+                &D_INTR.to_be_bytes(),
+                left,
+                right,
+            ]);
+        }
+
+        match node[..] == *self.root {
+            true => Ok(()),
+            false => Err(Error::LmsVerify),
+        }
+    }
+}
+
+/// HSS with one LMS tree per level, top level first. A single level is plain LMS in HSS framing.
+pub struct Hss {
+    levels: Vec<(LmsType, LmotsType)>,
+}
+
+impl Hss {
+    /// Up to eight levels, with at most 2^63 signatures in total.
+    pub fn new(levels: &[(LmsType, LmotsType)]) -> Result<Self, Error> {
+        let height: u32 = levels.iter().map(|(lms, _)| lms.h()).sum();
+
+        if levels.is_empty() || levels.len() > MAX_LEVELS || height > 63 {
+            return Err(Error::InvalidHssLevels);
+        }
+
+        Ok(Hss {
+            levels: levels.to_vec(),
+        })
+    }
+}
+
+struct Level {
+    /// Signature index bits above this level, which identify its tree.
+    prefix: u64,
+    tree: Tree,
+    /// The parent's signature over this tree's public key, followed by the key.
+    signed_public_key: Vec<u8>,
+}
+
+/// Trees below the top level are derived from their parent's seed and rebuilt as signing moves
+/// past them; only the current tree of each level is kept.
+///
+/// Serializes to the level count, each level's LMS and LM-OTS type codes, then the top tree's
+/// `I` and `SEED`. The index is not part of the key; `StatefulSigner` stores it alongside.
+pub struct SigningKey {
+    top: Tree,
+    levels: Vec<(LmsType, LmotsType)>,
+    cache: Vec<Option<Level>>,
+}
+
+impl SigningKey {
+    fn new(levels: Vec<(LmsType, LmotsType)>, id: [u8; ID_LEN], seed: Zeroizing<[u8; N]>) -> Self {
+        let (lms, ots) = levels[0];
+        SigningKey {
+            top: Tree::new(lms, ots, id, seed),
+            cache: (0..levels.len()).map(|_| None).collect(),
+            levels,
+        }
+    }
+}
+
+impl ToBytes for SigningKey {
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut res = (self.levels.len() as u32).to_be_bytes().to_vec();
+        for (lms, ots) in &self.levels {
+            res.extend((*lms as u32).to_be_bytes());
+            res.extend((*ots as u32).to_be_bytes());
+        }
+        res.extend(self.top.id);
+        res.extend(*self.top.seed);
+        res
+    }
+}
+
+impl TryFrom<&[u8]> for SigningKey {
+    type Error = Error;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        let mut r = Reader {
+            bytes: value,
+            offset: 0,
+            field: "HSS signing key",
+        };
+
+        let count = r.u32()? as usize;
+        if count == 0 || count > MAX_LEVELS {
+            return Err(r.error(0));
+        }
+        let levels = (0..count)
+            .map(|_| Ok((r.lms_type()?, r.lmots_type()?)))
+            .collect::<Result<Vec<_>, Error>>()?;
+        Hss::new(&levels).map_err(|_| r.error(0))?;
+
+        let id = r.take(ID_LEN)?.try_into().unwrap();
+        let seed = Zeroizing::new(r.take(N)?.try_into().unwrap());
+        r.finish()?;
+
+        Ok(SigningKey::new(levels, id, seed))
+    }
+}
+
+pub struct VerifyingKey(Vec<u8>);
+
+impl ToBytes for VerifyingKey {
+    fn to_bytes(&self) -> Vec<u8> {
+        self.0.clone()
+    }
+}
+
+impl TryFrom<&[u8]> for VerifyingKey {
+    type Error = Error;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        let mut r = Reader {
+            bytes: value,
+            offset: 0,
+            field: "HSS verifying key",
+        };
+
+        let levels = r.u32()?;
+        if levels == 0 || levels as usize > MAX_LEVELS {
+            return Err(r.error(0));
+        }
+        r.lms_public()?;
+        r.finish()?;
+
+        Ok(VerifyingKey(value.to_vec()))
+    }
+}
+
+pub struct Signature(Vec<u8>);
+
+impl ToBytes for Signature {
+    fn to_bytes(&self) -> Vec<u8> {
+        self.0.clone()
+    }
+}
+
+/// `Nspk` signed public keys, then the signature over the message.
+fn read_signature(
+    value: &[u8],
+) -> Result<(Vec<(LmsSignature<'_>, LmsPublic<'_>)>, LmsSignature<'_>), Error> {
+    let mut r = Reader {
+        bytes: value,
+        offset: 0,
+        field: "HSS signature",
+    };
+
+    let nspk = r.u32()? as usize;
+    if nspk >= MAX_LEVELS {
+        return Err(r.error(0));
+    }
+
+    let signed = (0..nspk)
+        .map(|_| Ok((r.lms_signature()?, r.lms_public()?)))
+        .collect::<Result<_, Error>>()?;
+    let last = r.lms_signature()?;
+    r.finish()?;
+
+    Ok((signed, last))
+}
+
+impl TryFrom<&[u8]> for Signature {
+    type Error = Error;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        read_signature(value)?;
+        Ok(Signature(value.to_vec()))
+    }
+}
+
+impl StatefulSigningScheme for Hss {
+    type SigningKey = SigningKey;
+
+    type VerifyingKey = VerifyingKey;
+
+    type Signature = Signature;
+
+    fn keygen_with_rng(
+        &mut self,
+        rng: &mut dyn CryptoRngCore,
+    ) -> Result<(Self::SigningKey, Self::VerifyingKey), Error> {
+        let mut id = [0; ID_LEN];
+        let mut seed = Zeroizing::new([0; N]);
+        rng.fill_bytes(&mut id);
+        rng.fill_bytes(&mut *seed);
+
+        let sk = SigningKey::new(self.levels.clone(), id, seed);
+        let pk = self.public_key(&sk)?;
+
+        Ok((sk, pk))
+    }
+
+    fn public_key(&self, sk: &Self::SigningKey) -> Result<Self::VerifyingKey, Error> {
+        let mut res = (sk.levels.len() as u32).to_be_bytes().to_vec();
+        res.extend(sk.top.public_key());
+        Ok(VerifyingKey(res))
+    }
+
+    fn capacity(&self, sk: &Self::SigningKey) -> u64 {
+        1 << sk.levels.iter().map(|(lms, _)| lms.h()).sum::<u32>()
+    }
+
+    fn sign(
+        &mut self,
+        sk: &mut Self::SigningKey,
+        index: Index,
+        m: &[u8],
+    ) -> Result<Self::Signature, Error> {
+        let index = index.get();
+        if index >= self.capacity(sk) {
+            return Err(Error::StateExhausted);
+        }
+
+        // Leaf used at each level, and the index bits above it that pick the level's tree
+        let mut rest = index;
+        let mut q = vec![0; sk.levels.len()];
+        let mut prefix = vec![0; sk.levels.len()];
+        for (l, (lms, _)) in sk.levels.iter().enumerate().rev() {
+            q[l] = (rest & ((1 << lms.h()) - 1)) as u32;
+            rest >>= lms.h();
+            prefix[l] = rest;
+        }
+
+        for l in 1..sk.levels.len() {
+            if sk.cache[l]
+                .as_ref()
+                .is_some_and(|level| level.prefix == prefix[l])
+            {
+                continue;
+            }
+
+            let parent = match &sk.cache[l - 1] {
+                Some(level) if l > 1 => &level.tree,
+                _ => &sk.top,
+            };
+            let tree = parent.child(q[l - 1], sk.levels[l]);
+            let public_key = tree.public_key();
+
+            let mut signed_public_key = parent.sign(q[l - 1], &public_key);
+            signed_public_key.extend(public_key);
+
+            sk.cache[l] = Some(Level {
+                prefix: prefix[l],
+                tree,
+                signed_public_key,
+            });
+        }
+
+        let mut res = ((sk.levels.len() - 1) as u32).to_be_bytes().to_vec();
+        for level in sk.cache.iter().flatten() {
+            res.extend(&level.signed_public_key);
+        }
+
+        let bottom = match sk.cache.last() {
+            Some(Some(level)) => &level.tree,
+            _ => &sk.top,
+        };
+        res.extend(bottom.sign(q[sk.levels.len() - 1], m));
+
+        Ok(Signature(res))
+    }
+
+    fn verify(
+        &mut self,
+        pk: &Self::VerifyingKey,
+        m: &[u8],
+        t: &Self::Signature,
+    ) -> Result<(), Error> {
+        let levels = u32::from_be_bytes(pk.0[..4].try_into().unwrap()) as usize;
+        let mut key = Reader {
+            bytes: &pk.0[4..],
+            offset: 0,
+            field: "HSS verifying key",
+        }
+        .lms_public()?;
+
+        let (signed, last) = read_signature(&t.0)?;
+        if signed.len() + 1 != levels {
+            return Err(Error::LmsVerify);
+        }
+
+        for (sig, next) in signed {
+            key.verify(next.bytes, &sig)?;
+            key = next;
+        }
+
+        key.verify(m, &last)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha20Rng;
+    use serde_json::Value;
+
+    use super::*;
+    use crate::kat;
+
+    const M: &[u8] = b"LMS round trip";
+
+    fn rfc8554() -> Value {
+        serde_json::from_str(&kat::read("LMS-HSS-RFC8554.json")).unwrap()
+    }
+
+    #[test]
+    fn rfc8554_signatures_verify() {
+        let vectors = rfc8554();
+        let mut hss = Hss::new(&[(LmsType::Sha256M32H5, LmotsType::Sha256N32W8)]).unwrap();
+
+        for case in ["testCase1", "testCase2"] {
+            let v = &vectors[case];
+            let pk = VerifyingKey::try_from(&kat::hex(&v["publicKey"])[..]).unwrap();
+            let t = Signature::try_from(&kat::hex(&v["signature"])[..]).unwrap();
+            let m = kat::hex(&v["message"]);
+
+            assert_eq!(hss.verify(&pk, &m, &t), Ok(()), "{case}");
+            assert_eq!(
+                hss.verify(&pk, &m[1..], &t),
+                Err(Error::LmsVerify),
+                "{case}"
+            );
+        }
+    }
+
+    /// Test case 2 gives both trees' `SEED` and `I`, so its signature can be rebuilt from the
+    /// randomizers it carries.
+    #[test]
+    fn rfc8554_test_case_2_is_reproduced() {
+        let v = &rfc8554()["testCase2"];
+        let expected = kat::hex(&v["signature"]);
+        let tree = |v: &Value, lms, ots| {
+            let id = kat::hex(&v["id"]).try_into().unwrap();
+            Tree::new(
+                lms,
+                ots,
+                id,
+                Zeroizing::new(kat::hex(&v["seed"]).try_into().unwrap()),
+            )
+        };
+
+        let top = tree(&v["top"], LmsType::Sha256M32H10, LmotsType::Sha256N32W4);
+        assert_eq!(top.public_key(), kat::hex(&v["publicKey"])[4..]);
+        let child = tree(&v["child"], LmsType::Sha256M32H5, LmotsType::Sha256N32W8);
+
+        let (signed, last) = read_signature(&expected).unwrap();
+        let (top_sig, child_pk) = &signed[0];
+        assert_eq!(child.public_key(), child_pk.bytes);
+
+        let mut t = 1u32.to_be_bytes().to_vec();
+        t.extend(top.sign_with(top_sig.q, top_sig.c.try_into().unwrap(), child_pk.bytes));
+        t.extend(child_pk.bytes);
+        t.extend(child.sign_with(last.q, last.c.try_into().unwrap(), &kat::hex(&v["message"])));
+        assert_eq!(t, expected);
+    }
+
+    #[test]
+    fn signing_keys_round_trip() {
+        let levels = [
+            (LmsType::Sha256M32H5, LmotsType::Sha256N32W8),
+            (LmsType::Sha256M32H5, LmotsType::Sha256N32W4),
+        ];
+        let mut hss = Hss::new(&levels).unwrap();
+        let (mut sk, pk) = hss
+            .keygen_with_rng(&mut ChaCha20Rng::seed_from_u64(0))
+            .unwrap();
+
+        let bytes = sk.to_bytes();
+        assert_eq!(bytes.len(), 4 + 2 * 8 + ID_LEN + N);
+        let mut decoded = SigningKey::try_from(&bytes[..]).unwrap();
+        assert_eq!(hss.public_key(&decoded).unwrap().to_bytes(), pk.to_bytes());
+
+        // Signing is deterministic, so both copies give the same signature at the same index
+        let t = hss.sign(&mut sk, Index(40), M).unwrap();
+        let t_decoded = hss.sign(&mut decoded, Index(40), M).unwrap();
+        assert_eq!(t.to_bytes(), t_decoded.to_bytes());
+        assert_eq!(hss.verify(&pk, M, &t), Ok(()));
+
+        let mut unknown_type = bytes.clone();
+        unknown_type[4..8].copy_from_slice(&8u32.to_be_bytes());
+        for bad in [&bytes[..bytes.len() - 1], &unknown_type, &[0; 4]] {
+            assert!(matches!(
+                SigningKey::try_from(bad),
+                Err(Error::Decode { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn indices_past_the_last_tree_are_refused() {
+        let mut hss = Hss::new(&[(LmsType::Sha256M32H5, LmotsType::Sha256N32W8)]).unwrap();
+        let (mut sk, pk) = hss
+            .keygen_with_rng(&mut ChaCha20Rng::seed_from_u64(1))
+            .unwrap();
+        assert_eq!(hss.capacity(&sk), 32);
+
+        let t = hss.sign(&mut sk, Index(31), M).unwrap();
+        assert_eq!(hss.verify(&pk, M, &t), Ok(()));
+        assert_eq!(
+            hss.sign(&mut sk, Index(32), M).map(|_| ()),
+            Err(Error::StateExhausted)
+        );
+    }
+}
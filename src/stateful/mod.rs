@@ -0,0 +1,360 @@
+pub mod lms;
+pub mod xmss;
+
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{Read, Write},
+    path::{Path, PathBuf},
+};
+
+use rand_core::CryptoRngCore;
+use zeroize::Zeroizing;
+
+use crate::{signing_scheme::ToBytes, Error};
+
+/// A one-time key index that has already been recorded as used on disk. Only `StatefulSigner`
+/// can create one, so a scheme never signs with an index that might be handed out again.
+#[derive(Debug)]
+pub struct Index(u64);
+
+impl Index {
+    pub fn get(&self) -> u64 {
+        self.0
+    }
+}
+
+/// A hash-based scheme whose signing key is a sequence of one-time keys (SP 800-208).
+///
+/// Unlike `SigningScheme::sign`, signing consumes an `Index`; go through `StatefulSigner`.
+pub trait StatefulSigningScheme {
+    /// Serializes without the index, which is not part of the key.
+    type SigningKey: ToBytes + for<'a> TryFrom<&'a [u8], Error = Error>;
+    type VerifyingKey: ToBytes + for<'a> TryFrom<&'a [u8], Error = Error>;
+    type Signature: ToBytes + for<'a> TryFrom<&'a [u8], Error = Error>;
+
+    fn keygen_with_rng(
+        &mut self,
+        rng: &mut dyn CryptoRngCore,
+    ) -> Result<(Self::SigningKey, Self::VerifyingKey), Error>;
+    fn public_key(&self, sk: &Self::SigningKey) -> Result<Self::VerifyingKey, Error>;
+    /// Number of one-time keys in `sk`.
+    fn capacity(&self, sk: &Self::SigningKey) -> u64;
+    /// `sk` is mutable so that implementations can cache the trees they rebuild.
+    fn sign(
+        &mut self,
+        sk: &mut Self::SigningKey,
+        index: Index,
+        m: &[u8],
+    ) -> Result<Self::Signature, Error>;
+    fn verify(
+        &mut self,
+        pk: &Self::VerifyingKey,
+        m: &[u8],
+        t: &Self::Signature,
+    ) -> Result<(), Error>;
+}
+
+/// Signs with a stateful key, recording every index on disk before its signature is released.
+///
+/// The state file holds the next unused index followed by the signing key, and is the only
+/// place the key lives: `generate` writes it as the key is made, so no key can be tracked by two
+/// state files. While a signer is alive it holds `<path>.lock`, which keeps a second signer from
+/// handing out the same indices. A crash leaves the lock behind; remove it by hand once sure no
+/// other signer is running. Indices reserved by a failed signing attempt are never reused.
+pub struct StatefulSigner<S: StatefulSigningScheme> {
+    scheme: S,
+    sk: S::SigningKey,
+    path: PathBuf,
+    next: u64,
+    capacity: u64,
+    _lock: Lock,
+}
+
+fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(suffix);
+    PathBuf::from(name)
+}
+
+/// Replaces the file at `path` so that a crash leaves either the old or the new contents.
+fn write_atomic(path: &Path, contents: &[u8]) -> Result<(), Error> {
+    let tmp = with_suffix(path, ".tmp");
+
+    let mut options = OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    // The file holds a signing key
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+    let mut file = options.open(&tmp)?;
+    file.write_all(contents)?;
+    file.sync_all()?;
+    fs::rename(&tmp, path)?;
+
+    // The rename itself is only durable once the directory is synced too
+    #[cfg(unix)]
+    if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+        File::open(dir)?.sync_all()?;
+    }
+
+    Ok(())
+}
+
+/// `<path>.lock`, removed again on drop.
+struct Lock(PathBuf);
+
+impl Lock {
+    fn acquire(path: &Path) -> Result<Self, Error> {
+        let lock = with_suffix(path, ".lock");
+
+        match OpenOptions::new().write(true).create_new(true).open(&lock) {
+            Ok(_) => Ok(Lock(lock)),
+            Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => Err(Error::StateLocked),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+impl Drop for Lock {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.0);
+    }
+}
+
+impl<S: StatefulSigningScheme> StatefulSigner<S> {
+    /// Generates a key straight into a new state file at `path`. Fails if the file exists.
+    pub fn generate(
+        mut scheme: S,
+        rng: &mut dyn CryptoRngCore,
+        path: impl AsRef<Path>,
+    ) -> Result<(Self, S::VerifyingKey), Error> {
+        let path = path.as_ref();
+        // Taken first so that the file cannot appear between the check and the write
+        let lock = Lock::acquire(path)?;
+
+        if path.exists() {
+            return Err(std::io::Error::from(std::io::ErrorKind::AlreadyExists).into());
+        }
+
+        let (sk, pk) = scheme.keygen_with_rng(rng)?;
+        let signer = StatefulSigner {
+            capacity: scheme.capacity(&sk),
+            scheme,
+            sk,
+            path: path.to_path_buf(),
+            next: 0,
+            _lock: lock,
+        };
+
+        signer.persist(0)?;
+        Ok((signer, pk))
+    }
+
+    /// Resumes signing with the key in the state file at `path`.
+    pub fn open(scheme: S, path: impl AsRef<Path>) -> Result<Self, Error> {
+        let path = path.as_ref();
+        // Taken first so that the state cannot change between reading and using it
+        let lock = Lock::acquire(path)?;
+
+        let mut contents = Zeroizing::new(Vec::new());
+        File::open(path)?.read_to_end(&mut contents)?;
+
+        let (next, sk) = contents.split_at_checked(8).ok_or(Error::Decode {
+            field: "signer state",
+            offset: contents.len(),
+        })?;
+        let sk = S::SigningKey::try_from(sk).map_err(|e| match e {
+            // Report offsets relative to the state file, not the key
+            Error::Decode { field, offset } => Error::Decode {
+                field,
+                offset: offset + next.len(),
+            },
+            e => e,
+        })?;
+
+        Ok(StatefulSigner {
+            capacity: scheme.capacity(&sk),
+            scheme,
+            sk,
+            path: path.to_path_buf(),
+            next: u64::from_be_bytes(next.try_into().unwrap()),
+            _lock: lock,
+        })
+    }
+
+    fn persist(&self, next: u64) -> Result<(), Error> {
+        let mut contents = Zeroizing::new(next.to_be_bytes().to_vec());
+        contents.extend(&*Zeroizing::new(self.sk.to_bytes()));
+        write_atomic(&self.path, &contents)
+    }
+
+    pub fn scheme(&mut self) -> &mut S {
+        &mut self.scheme
+    }
+
+    pub fn verifying_key(&self) -> Result<S::VerifyingKey, Error> {
+        self.scheme.public_key(&self.sk)
+    }
+
+    /// One-time keys left.
+    pub fn remaining(&self) -> u64 {
+        self.capacity.saturating_sub(self.next)
+    }
+
+    pub fn sign(&mut self, m: &[u8]) -> Result<S::Signature, Error> {
+        if self.next >= self.capacity {
+            return Err(Error::StateExhausted);
+        }
+
+        let index = self.next;
+        self.persist(index + 1)?;
+        self.next = index + 1;
+
+        self.scheme.sign(&mut self.sk, Index(index), m)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, process};
+
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha20Rng;
+
+    use super::*;
+    use crate::stateful::{
+        lms::{Hss, LmotsType, LmsType},
+        xmss::{XmssMt, XmssMtParams},
+    };
+
+    const M: &[u8] = b"stateful round trip";
+
+    /// A fresh state file path, with anything an earlier run left there removed.
+    fn state_path(name: &str) -> PathBuf {
+        let path = env::temp_dir().join(format!("pqsign-{}-{name}", process::id()));
+        for suffix in ["", ".lock", ".tmp"] {
+            let _ = fs::remove_file(with_suffix(&path, suffix));
+        }
+        path
+    }
+
+    /// Rewrites the next index in a state file, as if that many signatures had been made.
+    fn set_next(path: &Path, next: u64) {
+        let mut contents = fs::read(path).unwrap();
+        contents[..8].copy_from_slice(&next.to_be_bytes());
+        fs::write(path, contents).unwrap();
+    }
+
+    fn hss() -> Hss {
+        Hss::new(&[
+            (LmsType::Sha256M32H5, LmotsType::Sha256N32W8),
+            (LmsType::Sha256M32H5, LmotsType::Sha256N32W8),
+        ])
+        .unwrap()
+    }
+
+    fn xmss() -> XmssMt {
+        XmssMt::new(XmssMtParams::Sha2_20_4_256)
+    }
+
+    /// Signs, resumes from the state file and signs across the first tree boundary.
+    fn resumes<S: StatefulSigningScheme>(new: fn() -> S, name: &str) {
+        let path = state_path(name);
+        let mut rng = ChaCha20Rng::seed_from_u64(0);
+
+        let (mut signer, pk) = StatefulSigner::generate(new(), &mut rng, &path).unwrap();
+        let capacity = signer.remaining();
+        let first = signer.sign(M).unwrap();
+        assert_eq!(signer.scheme().verify(&pk, M, &first), Ok(()));
+        assert_eq!(signer.remaining(), capacity - 1);
+        drop(signer);
+
+        let mut signer = StatefulSigner::open(new(), &path).unwrap();
+        assert_eq!(signer.remaining(), capacity - 1);
+        assert_eq!(signer.verifying_key().unwrap().to_bytes(), pk.to_bytes());
+        let second = signer.sign(M).unwrap();
+        assert_ne!(first.to_bytes(), second.to_bytes());
+        assert_eq!(signer.scheme().verify(&pk, M, &second), Ok(()));
+        drop(signer);
+
+        // Indices 31 and 32 come from different bottom trees
+        set_next(&path, 31);
+        let mut signer = StatefulSigner::open(new(), &path).unwrap();
+        for _ in 0..2 {
+            let t = signer.sign(M).unwrap();
+            assert_eq!(signer.scheme().verify(&pk, M, &t), Ok(()));
+        }
+        assert_eq!(signer.remaining(), capacity - 33);
+        drop(signer);
+
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn signers_resume_from_their_state_file() {
+        resumes(hss, "hss-resume");
+        resumes(xmss, "xmss-resume");
+    }
+
+    fn exhausts<S: StatefulSigningScheme>(new: fn() -> S, name: &str) {
+        let path = state_path(name);
+        let mut rng = ChaCha20Rng::seed_from_u64(1);
+
+        let (signer, _) = StatefulSigner::generate(new(), &mut rng, &path).unwrap();
+        let capacity = signer.remaining();
+        drop(signer);
+
+        set_next(&path, capacity - 1);
+        let mut signer = StatefulSigner::open(new(), &path).unwrap();
+        assert!(signer.sign(M).is_ok());
+        assert_eq!(signer.remaining(), 0);
+        assert_eq!(signer.sign(M).map(|_| ()), Err(Error::StateExhausted));
+        drop(signer);
+
+        let mut signer = StatefulSigner::open(new(), &path).unwrap();
+        assert_eq!(signer.sign(M).map(|_| ()), Err(Error::StateExhausted));
+        drop(signer);
+
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn exhausted_keys_stop_signing() {
+        exhausts(hss, "hss-exhausted");
+        exhausts(xmss, "xmss-exhausted");
+    }
+
+    #[test]
+    fn a_key_has_one_state_file_and_one_signer() {
+        let path = state_path("exclusive");
+        let mut rng = ChaCha20Rng::seed_from_u64(2);
+
+        let (signer, _) = StatefulSigner::generate(hss(), &mut rng, &path).unwrap();
+        assert_eq!(
+            StatefulSigner::open(hss(), &path).map(|_| ()),
+            Err(Error::StateLocked)
+        );
+        assert_eq!(
+            StatefulSigner::generate(hss(), &mut rng, &path).map(|_| ()),
+            Err(Error::StateLocked)
+        );
+        drop(signer);
+
+        assert_eq!(
+            StatefulSigner::generate(hss(), &mut rng, &path).map(|_| ()),
+            Err(Error::StateIo(std::io::ErrorKind::AlreadyExists))
+        );
+        assert!(StatefulSigner::open(hss(), &path).is_ok());
+
+        fs::write(&path, [0; 7]).unwrap();
+        assert_eq!(
+            StatefulSigner::open(hss(), &path).map(|_| ()),
+            Err(Error::Decode {
+                field: "signer state",
+                offset: 7,
+            })
+        );
+
+        fs::remove_file(path).unwrap();
+    }
+}
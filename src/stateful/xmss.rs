@@ -0,0 +1,625 @@
+//! XMSS^MT (RFC 8391) with SHA2-256, n = 32 and w = 16.
+
+use rand_core::CryptoRngCore;
+use sha2::{Digest, Sha256};
+use zeroize::Zeroizing;
+
+use super::{Index, StatefulSigningScheme};
+use crate::{signing_scheme::ToBytes, Error};
+
+const N: usize = 32;
+const W: usize = 16;
+const LEN1: usize = 64;
+const LEN: usize = LEN1 + 3;
+const PUB_LEN: usize = 4 + 2 * N;
+
+const F: u8 = 0;
+const H: u8 = 1;
+const H_MSG: u8 = 2;
+const PRF: u8 = 3;
+const PRF_KEYGEN: u8 = 4;
+
+/// `toByte(tag, 32)` followed by the concatenation of `parts`.
+fn hash(tag: u8, parts: &[&[u8]]) -> [u8; N] {
+    let mut h = Sha256::new();
+    h.update([0; N - 1]);
+    h.update([tag]);
+    for part in parts {
+        h.update(part);
+    }
+    h.finalize().into()
+}
+
+fn to_byte(x: u64) -> [u8; N] {
+    let mut res = [0; N];
+    res[N - 8..].copy_from_slice(&x.to_be_bytes());
+    res
+}
+
+/// The XMSS^MT-SHA2_h/d_256 parameter sets, numbered by their OID. Each layer's tree is built
+/// whole and kept in memory, so the sets with 2^20-leaf layers (40/2 and 60/3) are left out.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum XmssMtParams {
+    Sha2_20_2_256 = 1,
+    Sha2_20_4_256 = 2,
+    Sha2_40_4_256 = 4,
+    Sha2_40_8_256 = 5,
+    Sha2_60_6_256 = 7,
+    Sha2_60_12_256 = 8,
+}
+
+impl XmssMtParams {
+    const ALL: [XmssMtParams; 6] = [
+        XmssMtParams::Sha2_20_2_256,
+        XmssMtParams::Sha2_20_4_256,
+        XmssMtParams::Sha2_40_4_256,
+        XmssMtParams::Sha2_40_8_256,
+        XmssMtParams::Sha2_60_6_256,
+        XmssMtParams::Sha2_60_12_256,
+    ];
+
+    fn from_oid(oid: u32) -> Option<Self> {
+        Self::ALL.into_iter().find(|p| *p as u32 == oid)
+    }
+
+    /// Total height; a key holds `2^h` one-time keys.
+    pub fn h(self) -> u32 {
+        match self {
+            XmssMtParams::Sha2_20_2_256 | XmssMtParams::Sha2_20_4_256 => 20,
+            XmssMtParams::Sha2_40_4_256 | XmssMtParams::Sha2_40_8_256 => 40,
+            XmssMtParams::Sha2_60_6_256 | XmssMtParams::Sha2_60_12_256 => 60,
+        }
+    }
+
+    /// Number of tree layers.
+    pub fn d(self) -> u32 {
+        match self {
+            XmssMtParams::Sha2_20_2_256 => 2,
+            XmssMtParams::Sha2_20_4_256 | XmssMtParams::Sha2_40_4_256 => 4,
+            XmssMtParams::Sha2_60_6_256 => 6,
+            XmssMtParams::Sha2_40_8_256 => 8,
+            XmssMtParams::Sha2_60_12_256 => 12,
+        }
+    }
+
+    fn tree_height(self) -> u32 {
+        self.h() / self.d()
+    }
+
+    fn idx_len(self) -> usize {
+        self.h().div_ceil(8) as usize
+    }
+
+    pub fn sig_len(self) -> usize {
+        self.idx_len() + N + self.d() as usize * (LEN + self.tree_height() as usize) * N
+    }
+}
+
+const OTS: u32 = 0;
+const LTREE: u32 = 1;
+const HASH_TREE: u32 = 2;
+
+/// Hash address: layer, tree (two words), type, then four type-specific words.
+#[derive(Clone, Copy)]
+struct Adrs([u32; 8]);
+
+impl Adrs {
+    fn new(layer: u32, tree: u64, ty: u32, index: u32) -> Self {
+        Adrs([layer, (tree >> 32) as u32, tree as u32, ty, index, 0, 0, 0])
+    }
+
+    /// Chain address of an OTS key, or tree height of an L-tree or hash tree node.
+    fn set_height(&mut self, x: u32) {
+        self.0[5] = x;
+    }
+
+    /// Hash address of an OTS key, or tree index of an L-tree or hash tree node.
+    fn set_index(&mut self, x: u32) {
+        self.0[6] = x;
+    }
+
+    fn prf(mut self, pub_seed: &[u8], key_and_mask: u32) -> [u8; N] {
+        self.0[7] = key_and_mask;
+        let mut bytes = [0; 32];
+        for (chunk, word) in bytes.chunks_exact_mut(4).zip(self.0) {
+            chunk.copy_from_slice(&word.to_be_bytes());
+        }
+        hash(PRF, &[pub_seed, &bytes])
+    }
+}
+
+fn xor(a: &[u8], b: &[u8; N]) -> [u8; N] {
+    std::array::from_fn(|i| a[i] ^ b[i])
+}
+
+fn chain(mut x: [u8; N], from: usize, to: usize, pub_seed: &[u8], mut adrs: Adrs) -> [u8; N] {
+    for j in from..to {
+        adrs.set_index(j as u32);
+        let key = adrs.prf(pub_seed, 0);
+        let mask = adrs.prf(pub_seed, 1);
+        x = hash(F, &[&key, &xor(&x, &mask)]);
+    }
+    x
+}
+
+fn rand_hash(left: &[u8], right: &[u8], pub_seed: &[u8], adrs: Adrs) -> [u8; N] {
+    let key = adrs.prf(pub_seed, 0);
+    let left = xor(left, &adrs.prf(pub_seed, 1));
+    let right = xor(right, &adrs.prf(pub_seed, 2));
+    hash(H, &[&key, &left, &right])
+}
+
+/// Base-16 digits of `m` followed by those of its checksum.
+fn digits(m: &[u8; N]) -> [usize; LEN] {
+    let mut res = [0; LEN];
+    for (i, byte) in m.iter().enumerate() {
+        res[2 * i] = (byte >> 4) as usize;
+        res[2 * i + 1] = (byte & 15) as usize;
+    }
+
+    let csum: usize = res[..LEN1].iter().map(|d| W - 1 - d).sum();
+    let csum = csum << 4;
+    res[LEN1] = (csum >> 12) & 15;
+    res[LEN1 + 1] = (csum >> 8) & 15;
+    res[LEN1 + 2] = (csum >> 4) & 15;
+    res
+}
+
+/// Compresses a WOTS+ public key into a leaf.
+fn ltree(mut pk: Vec<[u8; N]>, pub_seed: &[u8], mut adrs: Adrs) -> [u8; N] {
+    let mut height = 0;
+    while pk.len() > 1 {
+        adrs.set_height(height);
+        pk = pk
+            .chunks(2)
+            .enumerate()
+            .map(|(i, pair)| match pair {
+                [left, right] => {
+                    adrs.set_index(i as u32);
+                    rand_hash(left, right, pub_seed, adrs)
+                }
+                [odd] => *odd,
+                _ => unreachable!(),
+            })
+            .collect();
+        height += 1;
+    }
+    pk[0]
+}
+
+struct Seeds {
+    sk_seed: Zeroizing<[u8; N]>,
+    sk_prf: Zeroizing<[u8; N]>,
+    pub_seed: [u8; N],
+}
+
+impl Seeds {
+    fn wots_private(&self, mut adrs: Adrs, i: usize) -> Zeroizing<[u8; N]> {
+        adrs.set_height(i as u32);
+        adrs.set_index(0);
+        let mut bytes = [0; 32];
+        for (chunk, word) in bytes.chunks_exact_mut(4).zip(adrs.0) {
+            chunk.copy_from_slice(&word.to_be_bytes());
+        }
+        Zeroizing::new(hash(PRF_KEYGEN, &[&*self.sk_seed, &self.pub_seed, &bytes]))
+    }
+
+    fn wots_chain(&self, mut adrs: Adrs, i: usize, to: usize) -> [u8; N] {
+        let x = self.wots_private(adrs, i);
+        adrs.set_height(i as u32);
+        chain(*x, 0, to, &self.pub_seed, adrs)
+    }
+
+    fn leaf(&self, layer: u32, tree: u64, leaf: u32) -> [u8; N] {
+        let adrs = Adrs::new(layer, tree, OTS, leaf);
+        let pk = (0..LEN).map(|i| self.wots_chain(adrs, i, W - 1)).collect();
+        ltree(pk, &self.pub_seed, Adrs::new(layer, tree, LTREE, leaf))
+    }
+
+    /// Nodes numbered from the root at 1, with the children of `r` at `2r` and `2r + 1`.
+    fn tree(&self, layer: u32, tree: u64, height: u32) -> Vec<[u8; N]> {
+        let leaves = 1usize << height;
+        let mut nodes = vec![[0; N]; 2 * leaves];
+
+        for i in 0..leaves {
+            nodes[leaves + i] = self.leaf(layer, tree, i as u32);
+        }
+
+        let mut adrs = Adrs::new(layer, tree, HASH_TREE, 0);
+        for r in (1..leaves).rev() {
+            // Height of the children, and index of the parent within its level
+            let level = height - (r.ilog2() + 1);
+            adrs.set_height(level);
+            adrs.set_index((r - (1 << r.ilog2())) as u32);
+            nodes[r] = rand_hash(&nodes[2 * r], &nodes[2 * r + 1], &self.pub_seed, adrs);
+        }
+
+        nodes
+    }
+}
+
+/// Climbs from `leaf`'s node to the root along the authentication path `auth`.
+fn root_from_auth(
+    mut node: [u8; N],
+    leaf: u32,
+    auth: &[u8],
+    pub_seed: &[u8],
+    mut adrs: Adrs,
+) -> [u8; N] {
+    for (k, sibling) in auth.chunks_exact(N).enumerate() {
+        let index = leaf >> k;
+        adrs.set_height(k as u32);
+        adrs.set_index(index >> 1);
+        node = match index % 2 {
+            0 => rand_hash(&node, sibling, pub_seed, adrs),
+            _ => rand_hash(sibling, &node, pub_seed, adrs),
+        };
+    }
+    node
+}
+
+pub struct XmssMt {
+    params: XmssMtParams,
+}
+
+impl XmssMt {
+    pub fn new(params: XmssMtParams) -> Self {
+        XmssMt { params }
+    }
+}
+
+/// Only the current tree of each layer is kept; trees are rebuilt as signing moves past them.
+///
+/// Serializes to the OID, then `SK_SEED || SK_PRF || root || PUB_SEED` as in the RFC 8391
+/// private key but without its index, which `StatefulSigner` stores alongside.
+pub struct SigningKey {
+    params: XmssMtParams,
+    seeds: Seeds,
+    root: [u8; N],
+    cache: Vec<Option<(u64, Vec<[u8; N]>)>>,
+}
+
+impl ToBytes for SigningKey {
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut res = (self.params as u32).to_be_bytes().to_vec();
+        res.extend(*self.seeds.sk_seed);
+        res.extend(*self.seeds.sk_prf);
+        res.extend(self.root);
+        res.extend(self.seeds.pub_seed);
+        res
+    }
+}
+
+impl TryFrom<&[u8]> for SigningKey {
+    type Error = Error;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        let decode = |offset| Error::Decode {
+            field: "XMSS^MT signing key",
+            offset,
+        };
+
+        let (oid, rest) = value.split_at_checked(4).ok_or(decode(value.len()))?;
+        let params =
+            XmssMtParams::from_oid(u32::from_be_bytes(oid.try_into().unwrap())).ok_or(decode(0))?;
+        if rest.len() != 4 * N {
+            return Err(decode(value.len().min(4 + 4 * N)));
+        }
+
+        let part = |i: usize| -> [u8; N] { rest[i * N..(i + 1) * N].try_into().unwrap() };
+        Ok(SigningKey {
+            params,
+            seeds: Seeds {
+                sk_seed: Zeroizing::new(part(0)),
+                sk_prf: Zeroizing::new(part(1)),
+                pub_seed: part(3),
+            },
+            root: part(2),
+            cache: (0..params.d()).map(|_| None).collect(),
+        })
+    }
+}
+
+pub struct VerifyingKey(Vec<u8>);
+
+impl ToBytes for VerifyingKey {
+    fn to_bytes(&self) -> Vec<u8> {
+        self.0.clone()
+    }
+}
+
+impl TryFrom<&[u8]> for VerifyingKey {
+    type Error = Error;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        let decode = |offset| Error::Decode {
+            field: "XMSS^MT verifying key",
+            offset,
+        };
+
+        let (oid, rest) = value.split_at_checked(4).ok_or(decode(value.len()))?;
+        XmssMtParams::from_oid(u32::from_be_bytes(oid.try_into().unwrap())).ok_or(decode(0))?;
+
+        match rest.len() == 2 * N {
+            true => Ok(VerifyingKey(value.to_vec())),
+            false => Err(decode(value.len().min(PUB_LEN))),
+        }
+    }
+}
+
+/// Carries no OID; its length identifies the parameter set.
+pub struct Signature(Vec<u8>);
+
+impl ToBytes for Signature {
+    fn to_bytes(&self) -> Vec<u8> {
+        self.0.clone()
+    }
+}
+
+impl TryFrom<&[u8]> for Signature {
+    type Error = Error;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        match XmssMtParams::ALL.iter().any(|p| p.sig_len() == value.len()) {
+            true => Ok(Signature(value.to_vec())),
+            false => Err(Error::Decode {
+                field: "XMSS^MT signature",
+                offset: value.len(),
+            }),
+        }
+    }
+}
+
+impl StatefulSigningScheme for XmssMt {
+    type SigningKey = SigningKey;
+
+    type VerifyingKey = VerifyingKey;
+
+    type Signature = Signature;
+
+    fn keygen_with_rng(
+        &mut self,
+        rng: &mut dyn CryptoRngCore,
+    ) -> Result<(Self::SigningKey, Self::VerifyingKey), Error> {
+        let mut seeds = Seeds {
+            sk_seed: Zeroizing::new([0; N]),
+            sk_prf: Zeroizing::new([0; N]),
+            pub_seed: [0; N],
+        };
+        rng.fill_bytes(&mut *seeds.sk_seed);
+        rng.fill_bytes(&mut *seeds.sk_prf);
+        rng.fill_bytes(&mut seeds.pub_seed);
+
+        let d = self.params.d();
+        let top = seeds.tree(d - 1, 0, self.params.tree_height());
+
+        let mut cache: Vec<_> = (0..d).map(|_| None).collect();
+        let root = top[1];
+        cache[d as usize - 1] = Some((0, top));
+
+        let sk = SigningKey {
+            params: self.params,
+            seeds,
+            root,
+            cache,
+        };
+        let pk = self.public_key(&sk)?;
+
+        Ok((sk, pk))
+    }
+
+    fn public_key(&self, sk: &Self::SigningKey) -> Result<Self::VerifyingKey, Error> {
+        let mut res = (sk.params as u32).to_be_bytes().to_vec();
+        res.extend(sk.root);
+        res.extend(sk.seeds.pub_seed);
+        Ok(VerifyingKey(res))
+    }
+
+    fn capacity(&self, sk: &Self::SigningKey) -> u64 {
+        1 << sk.params.h()
+    }
+
+    fn sign(
+        &mut self,
+        sk: &mut Self::SigningKey,
+        index: Index,
+        m: &[u8],
+    ) -> Result<Self::Signature, Error> {
+        let idx = index.get();
+        if idx >= self.capacity(sk) {
+            return Err(Error::StateExhausted);
+        }
+
+        let params = sk.params;
+        let height = params.tree_height();
+        let idx_bytes = to_byte(idx);
+        let r = hash(PRF, &[&*sk.seeds.sk_prf, &idx_bytes]);
+
+        let mut res = idx.to_be_bytes()[8 - params.idx_len()..].to_vec();
+        res.extend(r);
+
+        let mut node = hash(H_MSG, &[&r, &sk.root, &idx_bytes, m]);
+        let mut tree = idx;
+
+        for layer in 0..params.d() {
+            let leaf = (tree & ((1 << height) - 1)) as u32;
+            tree >>= height;
+
+            let cached = &mut sk.cache[layer as usize];
+            if cached.as_ref().is_none_or(|(t, _)| *t != tree) {
+                *cached = Some((tree, sk.seeds.tree(layer, tree, height)));
+            }
+            let nodes = &cached.as_ref().unwrap().1;
+
+            let adrs = Adrs::new(layer, tree, OTS, leaf);
+            for (i, a) in digits(&node).into_iter().enumerate() {
+                res.extend(sk.seeds.wots_chain(adrs, i, a));
+            }
+
+            let leaves = 1usize << height;
+            for k in 0..height {
+                res.extend(nodes[((leaves + leaf as usize) >> k) ^ 1]);
+            }
+
+            node = nodes[1];
+        }
+
+        Ok(Signature(res))
+    }
+
+    fn verify(
+        &mut self,
+        pk: &Self::VerifyingKey,
+        m: &[u8],
+        t: &Self::Signature,
+    ) -> Result<(), Error> {
+        let params = XmssMtParams::from_oid(u32::from_be_bytes(pk.0[..4].try_into().unwrap()))
+            .ok_or(Error::XmssVerify)?;
+        let (root, pub_seed) = pk.0[4..].split_at(N);
+
+        if t.0.len() != params.sig_len() {
+            return Err(Error::XmssVerify);
+        }
+
+        let (idx, rest) = t.0.split_at(params.idx_len());
+        let idx = idx.iter().fold(0u64, |acc, b| (acc << 8) | *b as u64);
+        if idx >= 1 << params.h() {
+            return Err(Error::XmssVerify);
+        }
+
+        let (r, mut rest) = rest.split_at(N);
+        let height = params.tree_height();
+        let idx_bytes = to_byte(idx);
+
+        let mut node = hash(H_MSG, &[r, root, &idx_bytes, m]);
+        let mut tree = idx;
+
+        for layer in 0..params.d() {
+            let leaf = (tree & ((1 << height) - 1)) as u32;
+            tree >>= height;
+
+            let (wots, tail) = rest.split_at(LEN * N);
+            let (auth, tail) = tail.split_at(height as usize * N);
+            rest = tail;
+
+            let mut adrs = Adrs::new(layer, tree, OTS, leaf);
+            let pk = digits(&node)
+                .into_iter()
+                .zip(wots.chunks_exact(N))
+                .enumerate()
+                .map(|(i, (a, y))| {
+                    adrs.set_height(i as u32);
+                    chain(y.try_into().unwrap(), a, W - 1, pub_seed, adrs)
+                })
+                .collect();
+
+            node = ltree(pk, pub_seed, Adrs::new(layer, tree, LTREE, leaf));
+            node = root_from_auth(
+                node,
+                leaf,
+                auth,
+                pub_seed,
+                Adrs::new(layer, tree, HASH_TREE, 0),
+            );
+        }
+
+        match node[..] == *root {
+            true => Ok(()),
+            false => Err(Error::XmssVerify),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha20Rng;
+
+    use super::*;
+    use crate::kat::{self, Replay};
+
+    const M: &[u8] = b"XMSS round trip";
+
+    /// liboqs's `sig_stfl` KATs, from the XMSS reference implementation: key generation from
+    /// the seeds in `sk`, then the signature at index 0.
+    fn liboqs_kat(params: XmssMtParams, name: &str) {
+        let entries = kat::rsp(name);
+        let hex = |key: &str| {
+            let entry = entries.iter().find(|e| e.contains_key(key)).unwrap();
+            hex::decode(&entry[key]).unwrap()
+        };
+
+        // `sk` is the OID, the index, then `SK_SEED || SK_PRF || root || PUB_SEED`
+        let sk = hex("sk");
+        let secret = &sk[4 + params.idx_len()..];
+        let mut rng = Replay::new(&[&secret[..N], &secret[N..2 * N], &secret[3 * N..]]);
+
+        let mut xmss = XmssMt::new(params);
+        let (mut signing_key, pk) = xmss.keygen_with_rng(&mut rng).unwrap();
+        assert_eq!(pk.to_bytes(), hex("pk"), "{name}");
+        assert_eq!(
+            signing_key.to_bytes(),
+            [&sk[..4], secret].concat(),
+            "{name}"
+        );
+
+        // Despite its name, `sm` holds only the signature
+        let m = hex("msg");
+        let t = xmss.sign(&mut signing_key, Index(0), &m).unwrap();
+        assert_eq!(t.to_bytes(), hex("sm"), "{name}");
+        assert_eq!(xmss.verify(&pk, &m, &t), Ok(()), "{name}");
+    }
+
+    #[test]
+    fn liboqs_kats_are_reproduced() {
+        liboqs_kat(XmssMtParams::Sha2_20_4_256, "XMSSMT-SHA2_20-4_256.rsp");
+        liboqs_kat(XmssMtParams::Sha2_40_8_256, "XMSSMT-SHA2_40-8_256.rsp");
+        liboqs_kat(XmssMtParams::Sha2_60_12_256, "XMSSMT-SHA2_60-12_256.rsp");
+    }
+
+    #[test]
+    fn signing_keys_round_trip() {
+        let mut xmss = XmssMt::new(XmssMtParams::Sha2_20_4_256);
+        let (mut sk, pk) = xmss
+            .keygen_with_rng(&mut ChaCha20Rng::seed_from_u64(0))
+            .unwrap();
+
+        let bytes = sk.to_bytes();
+        assert_eq!(bytes.len(), 4 + 4 * N);
+        let mut decoded = SigningKey::try_from(&bytes[..]).unwrap();
+        assert_eq!(xmss.public_key(&decoded).unwrap().to_bytes(), pk.to_bytes());
+
+        // Signing is deterministic, so both copies give the same signature at the same index
+        let t = xmss.sign(&mut sk, Index(1000), M).unwrap();
+        let t_decoded = xmss.sign(&mut decoded, Index(1000), M).unwrap();
+        assert_eq!(t.to_bytes(), t_decoded.to_bytes());
+        assert_eq!(xmss.verify(&pk, M, &t), Ok(()));
+        assert_eq!(xmss.verify(&pk, &M[1..], &t), Err(Error::XmssVerify));
+
+        let mut unknown_oid = bytes.clone();
+        unknown_oid[..4].copy_from_slice(&3u32.to_be_bytes());
+        for bad in [&bytes[..bytes.len() - 1], &unknown_oid, &[0; 4]] {
+            assert!(matches!(
+                SigningKey::try_from(bad),
+                Err(Error::Decode { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn indices_past_the_last_tree_are_refused() {
+        let mut xmss = XmssMt::new(XmssMtParams::Sha2_20_4_256);
+        let (mut sk, pk) = xmss
+            .keygen_with_rng(&mut ChaCha20Rng::seed_from_u64(1))
+            .unwrap();
+        let capacity = xmss.capacity(&sk);
+        assert_eq!(capacity, 1 << 20);
+
+        let t = xmss.sign(&mut sk, Index(capacity - 1), M).unwrap();
+        assert_eq!(xmss.verify(&pk, M, &t), Ok(()));
+        assert_eq!(
+            xmss.sign(&mut sk, Index(capacity), M).map(|_| ()),
+            Err(Error::StateExhausted)
+        );
+    }
+}
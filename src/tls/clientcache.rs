@@ -1,67 +1,65 @@
-use std::{collections::HashMap, net::TcpStream};
+use std::{marker::PhantomData, net::TcpStream};
 
-use crate::{
-    falcon::{Degree, Falcon},
-    signing_scheme::{SigningScheme, ToBytes},
-};
+use rand_core::CryptoRngCore;
+
+use crate::{falcon::Falcon, signing_scheme::ToBytes, Error};
 
-use super::{read_bytes_stream, write_bytes_stream, Certificate, SignedCertificate, Tls};
+use super::{
+    decode, read_bytes_stream, verify_certificate, verify_encoded, write_bytes_stream, CertChain,
+    SignedCertificate, Tls, TlsScheme,
+};
 
-pub struct ClientCachingTls;
+pub struct ClientCachingTls<S = Falcon>(PhantomData<S>);
 
-pub struct ClientCtx {
-    falcon: Falcon,
-    pk_root: Vec<u8>,
-    pk_self: Vec<u8>,
-    sk_self: Vec<u8>,
+pub struct ClientCtx<S: TlsScheme> {
+    scheme: S,
+    pk_root: S::VerifyingKey,
+    pk_self: S::VerifyingKey,
+    sk_self: S::SigningKey,
     pk_server: Option<Vec<u8>>,
 }
 
-pub struct ServerCtx {
-    falcon: Falcon,
+pub struct ServerCtx<S: TlsScheme> {
+    scheme: S,
     cert_chain: Vec<SignedCertificate>,
-    sk_end: Vec<u8>,
+    sk_end: S::SigningKey,
     first: bool,
 }
 
-impl Tls for ClientCachingTls {
-    type CX = ClientCtx;
-    type SX = ServerCtx;
-    type S = Falcon;
+impl<S: TlsScheme> Tls for ClientCachingTls<S> {
+    type CX = ClientCtx<S>;
+    type SX = ServerCtx<S>;
+    type S = S;
+    type R = S;
 
-    fn new() -> (ClientCtx, ServerCtx) {
-        let (cert_chain, pk_root, _, sk_end) = Self::make_cert_chain();
+    fn new(rng: &mut dyn CryptoRngCore) -> Result<(ClientCtx<S>, ServerCtx<S>), Error> {
+        let (cert_chain, pk_root, _, sk_end) = Self::make_cert_chain(rng)?;
 
-        let mut falcon1 = Falcon::new(Degree::F512, Some("seed1".as_bytes()));
+        let mut scheme1 = S::from_rng(rng);
 
-        let (sk_self, pk_self) = falcon1.keygen();
+        let (sk_self, pk_self) = scheme1.keygen_with_rng(rng)?;
 
-        let falcon2 = Falcon::new(Degree::F512, Some("seed2".as_bytes()));
+        let scheme2 = S::from_rng(rng);
 
-        (
+        Ok((
             ClientCtx {
-                falcon: falcon1,
+                scheme: scheme1,
                 pk_root,
                 pk_self,
                 sk_self,
                 pk_server: None,
             },
             ServerCtx {
-                falcon: falcon2,
+                scheme: scheme2,
                 cert_chain,
                 sk_end,
                 first: true,
             },
-        )
+        ))
     }
 
-    fn make_cert_chain() -> (
-        Vec<SignedCertificate>,
-        <Self::S as SigningScheme>::VerifyingKey,
-        <Self::S as SigningScheme>::SigningKey,
-        <Self::S as SigningScheme>::SigningKey,
-    ) {
-        Self::_make_cert_chain(&mut Falcon::new(Degree::F512, Some("seed".as_bytes())))
+    fn make_cert_chain(rng: &mut dyn CryptoRngCore) -> Result<CertChain<Self::S, Self::R>, Error> {
+        Self::_make_cert_chain(&mut S::from_rng(rng), &mut S::from_rng(rng), rng)
     }
 
     fn client_transcript(ctx: &mut Self::CX, stream: &mut TcpStream) {
@@ -92,7 +90,7 @@ impl Tls for ClientCachingTls {
         }
     }
 
-    fn server_certificate_verify(ctx: &mut Self::SX, stream: &mut TcpStream) {
+    fn server_certificate_verify(ctx: &mut Self::SX, stream: &mut TcpStream) -> bool {
         let mut transcript_bytes =
             read_bytes_stream(stream, "server_certificate_verify_transcript");
 
@@ -100,8 +98,10 @@ impl Tls for ClientCachingTls {
             transcript_bytes.extend(ctx.cert_chain.iter().flat_map(|cert| cert.to_bytes()));
         }
 
-        let signature = ctx.falcon.sign(&ctx.sk_end, &transcript_bytes);
+        let signature = ctx.scheme.sign(&ctx.sk_end, &transcript_bytes).unwrap();
         write_bytes_stream(stream, &signature.to_bytes()).unwrap();
+
+        true
     }
 
     fn client_verify(ctx: &mut Self::CX, stream: &mut TcpStream) -> bool {
@@ -109,22 +109,27 @@ impl Tls for ClientCachingTls {
 
         if ctx.pk_server.is_none() {
             for i in 0..3 {
-                let cert: SignedCertificate =
-                    read_bytes_stream(stream, &format!("client_verify_cert_{i}")).into();
-                certificate_chain.push(cert.clone());
+                let bytes = read_bytes_stream(stream, &format!("client_verify_cert_{i}"));
+                match SignedCertificate::try_from(&bytes[..]) {
+                    Ok(cert) => certificate_chain.push(cert),
+                    Err(e) => {
+                        println!("[client_verify] Malformed certificate {i}: {e}");
+                        return false;
+                    }
+                }
                 // println!("[client_verify] Received certificate {i}");
             }
 
             // Verify root cert is signed by pk_root
 
-            let status = ctx.falcon.verify(
+            let status = verify_certificate(
+                &mut ctx.scheme,
                 &ctx.pk_root,
-                &certificate_chain.last().unwrap().certificate.to_bytes(),
-                &certificate_chain.last().unwrap().signature.clone().into(),
+                certificate_chain.last().unwrap(),
             );
 
-            if !status {
-                println!("[client_verify] Root cert verification failed");
+            if let Err(e) = status {
+                println!("[client_verify] Root cert verification failed: {e}");
                 return false;
             }
 
@@ -138,16 +143,16 @@ impl Tls for ClientCachingTls {
         let mut m: Vec<u8> = "transcript-hash".into();
         m.extend(certificate_chain.iter().flat_map(|cert| cert.to_bytes()));
 
-        let status = ctx.falcon.verify(
-            ctx.pk_server
-                .as_ref()
-                .unwrap_or_else(|| &certificate_chain[0].certificate.subject_pk),
-            &m,
-            &certificate_verify.into(),
-        );
+        let pk_server = ctx
+            .pk_server
+            .as_ref()
+            .unwrap_or_else(|| &certificate_chain[0].certificate.subject_pk);
+
+        let status = decode(pk_server)
+            .and_then(|pk| verify_encoded(&mut ctx.scheme, &pk, &m, &certificate_verify));
 
-        if !status {
-            println!("[client_verify] certificate verify check failed");
+        if let Err(e) = status {
+            println!("[client_verify] certificate verify check failed: {e}");
             return false;
         }
 
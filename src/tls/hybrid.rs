@@ -0,0 +1,74 @@
+use crate::{composite::Composite, eddsa::Eddsa, falcon::Falcon};
+
+use super::pqc::PqcTls;
+
+/// The PQC handshake with every certificate and the transcript signed by both Ed25519 and `S`.
+pub type HybridTls<S = Falcon> = PqcTls<Composite<Eddsa, S>>;
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::Write,
+        net::{TcpListener, TcpStream},
+        thread,
+    };
+
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha20Rng;
+
+    use super::*;
+    use crate::{
+        signing_scheme::ToBytes,
+        tls::{verify_encoded, Tls, TlsScheme},
+    };
+
+    #[test]
+    fn handshake_verifies_over_loopback() {
+        let mut rng = ChaCha20Rng::seed_from_u64(0);
+        let (mut cx, mut sx) = HybridTls::<Falcon>::new(&mut rng).unwrap();
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let verified = thread::scope(|s| {
+            s.spawn(|| {
+                let (mut stream, _) = listener.accept().unwrap();
+                HybridTls::<Falcon>::server_certificate(&mut sx, &mut stream);
+                stream.flush().unwrap();
+                assert!(HybridTls::<Falcon>::server_certificate_verify(
+                    &mut sx,
+                    &mut stream
+                ));
+                stream.flush().unwrap();
+            });
+
+            let mut stream = TcpStream::connect(addr).unwrap();
+            HybridTls::<Falcon>::client_transcript(&mut cx, &mut stream);
+            stream.flush().unwrap();
+            HybridTls::<Falcon>::client_verify(&mut cx, &mut stream)
+        });
+
+        assert!(verified);
+    }
+
+    /// Every certificate carries both signatures; breaking either one fails the chain.
+    #[test]
+    fn certificates_need_both_signatures() {
+        let mut rng = ChaCha20Rng::seed_from_u64(1);
+        let (certs, pk_root, _, _) = HybridTls::<Falcon>::make_cert_chain(&mut rng).unwrap();
+        let mut root = Composite::<Eddsa, Falcon>::from_rng(&mut rng);
+
+        let root_cert = &certs[2];
+        let m = root_cert.certificate.to_bytes();
+        assert_eq!(
+            verify_encoded(&mut root, &pk_root, &m, &root_cert.signature),
+            Ok(())
+        );
+
+        // A 4-byte length, the 64-byte Ed25519 signature, then the Falcon one
+        for at in [4 + 10, 4 + 64 + 10] {
+            let mut t = root_cert.signature.clone();
+            t[at] ^= 1;
+            assert!(verify_encoded(&mut root, &pk_root, &m, &t).is_err(), "{at}");
+        }
+    }
+}
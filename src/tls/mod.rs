@@ -1,7 +1,8 @@
+pub mod clientcache;
+pub mod hybrid;
 pub mod plain;
 pub mod pqc;
 pub mod pqccache;
-pub mod clientcache;
 
 use std::{
     io::{Read, Write},
@@ -9,12 +10,82 @@ use std::{
     net::TcpStream,
 };
 
-use crate::signing_scheme::{SigningScheme, ToBytes};
+use ml_dsa::EncodedVerifyingKey;
+use rand_core::CryptoRngCore;
+
+use crate::{
+    composite::Composite,
+    ecdsa::{Curve, Ecdsa},
+    eddsa::Eddsa,
+    falcon::{Degree, Falcon},
+    mldsa::{self, MlDsa},
+    registry::AlgorithmId,
+    rsapss::RsaPss,
+    signing_scheme::{SigningScheme, ToBytes},
+    slhdsa::{Params, SlhDsa},
+    Error,
+};
+
+/// Reads a length-prefixed `field` starting at `start`, returning it and the offset just past it.
+fn read_bytes<'a>(
+    value: &'a [u8],
+    start: usize,
+    field: &'static str,
+) -> Result<(&'a [u8], usize), Error> {
+    let len_bytes = value
+        .get(start..start.saturating_add(size_of::<usize>()))
+        .and_then(|t| t.try_into().ok())
+        .ok_or(Error::Decode {
+            field,
+            offset: start,
+        })?;
+    let len = usize::from_be_bytes(len_bytes);
+    let start = start + size_of::<usize>();
+    let t = value
+        .get(start..start.saturating_add(len))
+        .ok_or(Error::Decode {
+            field,
+            offset: start,
+        })?;
+    Ok((t, start + len))
+}
+
+fn read_string(value: &[u8], start: usize, field: &'static str) -> Result<(String, usize), Error> {
+    let (t, end) = read_bytes(value, start, field)?;
+    let s = std::str::from_utf8(t).map_err(|e| Error::Decode {
+        field,
+        offset: end - t.len() + e.valid_up_to(),
+    })?;
+    Ok((s.to_string(), end))
+}
+
+fn decode<T: for<'a> TryFrom<&'a [u8], Error = Error>>(bytes: &[u8]) -> Result<T, Error> {
+    T::try_from(bytes)
+}
+
+fn verify_encoded<S: SigningScheme>(
+    scheme: &mut S,
+    pk: &S::VerifyingKey,
+    m: &[u8],
+    t: &[u8],
+) -> Result<(), Error> {
+    scheme.verify(pk, m, &decode(t)?)
+}
+
+/// Checks that `cert` declares `S` as its signature algorithm and that the signature verifies.
+fn verify_certificate<S: TlsScheme>(
+    scheme: &mut S,
+    pk: &S::VerifyingKey,
+    cert: &SignedCertificate,
+) -> Result<(), Error> {
+    if cert.certificate.algorithm != S::ALGORITHM {
+        return Err(Error::AlgorithmMismatch {
+            expected: S::ALGORITHM,
+            found: cert.certificate.algorithm,
+        });
+    }
 
-fn read_bytes(value: &Vec<u8>, mut start: usize) -> (&[u8], usize) {
-    let len = usize::from_be_bytes(value[start..start + size_of::<usize>()].try_into().unwrap());
-    start += size_of::<usize>();
-    (&value[start..start + len], start + len)
+    verify_encoded(scheme, pk, &cert.certificate.to_bytes(), &cert.signature)
 }
 
 fn read_bytes_stream(reader: &mut impl Read, _dbg: &str) -> Vec<u8> {
@@ -39,19 +110,33 @@ fn write_bytes_stream(writer: &mut impl Write, data: &[u8]) -> std::io::Result<u
 
 #[derive(Debug, Clone)]
 struct Certificate {
+    /// The algorithm of the issuer's signature, which is signed along with the rest.
+    algorithm: AlgorithmId,
     issuer_name: String,
     subject_name: String,
     subject_pk: Vec<u8>,
 }
 
 impl Certificate {
-    pub fn sign<S: SigningScheme>(self, scheme: &mut S, sk: &S::SigningKey) -> SignedCertificate {
-        let signature = scheme.sign(sk, &self.to_bytes());
+    /// Fails with `Error::AlgorithmMismatch` unless `S` is the declared algorithm.
+    pub fn sign<S: TlsScheme>(
+        self,
+        scheme: &mut S,
+        sk: &S::SigningKey,
+    ) -> Result<SignedCertificate, Error> {
+        if self.algorithm != S::ALGORITHM {
+            return Err(Error::AlgorithmMismatch {
+                expected: self.algorithm,
+                found: S::ALGORITHM,
+            });
+        }
+
+        let signature = scheme.sign(sk, &self.to_bytes())?;
 
-        SignedCertificate {
+        Ok(SignedCertificate {
             certificate: self,
             signature: signature.to_bytes(),
-        }
+        })
     }
 }
 
@@ -59,6 +144,7 @@ impl ToBytes for Certificate {
     fn to_bytes(&self) -> Vec<u8> {
         let mut res = Vec::new();
 
+        res.extend(self.algorithm.code().to_be_bytes());
         res.extend(self.issuer_name.len().to_be_bytes());
         res.extend(self.issuer_name.as_bytes());
         res.extend(self.subject_name.len().to_be_bytes());
@@ -70,20 +156,36 @@ impl ToBytes for Certificate {
     }
 }
 
-impl From<Vec<u8>> for Certificate {
-    fn from(value: Vec<u8>) -> Self {
-        let (t, start) = read_bytes(&value, 0);
-        let issuer_name = String::from_utf8(t.into()).unwrap();
-        let (t, start) = read_bytes(&value, start);
-        let subject_name = String::from_utf8(t.into()).unwrap();
-        let (t, _) = read_bytes(&value, start);
+impl TryFrom<&[u8]> for Certificate {
+    type Error = Error;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        let code = value
+            .get(..size_of::<u16>())
+            .and_then(|t| t.try_into().ok())
+            .ok_or(Error::Decode {
+                field: "algorithm",
+                offset: 0,
+            })?;
+        let algorithm = AlgorithmId::try_from(u16::from_be_bytes(code))?;
+        let (issuer_name, start) = read_string(value, size_of::<u16>(), "issuer_name")?;
+        let (subject_name, start) = read_string(value, start, "subject_name")?;
+        let (t, end) = read_bytes(value, start, "subject_pk")?;
         let subject_pk = t.into();
 
-        Self {
+        if end != value.len() {
+            return Err(Error::Decode {
+                field: "certificate",
+                offset: end,
+            });
+        }
+
+        Ok(Self {
+            algorithm,
             issuer_name,
             subject_name,
             subject_pk,
-        }
+        })
     }
 }
 
@@ -97,6 +199,7 @@ impl ToBytes for SignedCertificate {
     /**
      * Format
      * ------
+     * algorithm (2 bytes)
      * [issuer_name len] issuer_name
      * [subject_name len] subject_name
      * ...
@@ -117,79 +220,272 @@ impl ToBytes for SignedCertificate {
     }
 }
 
-impl From<Vec<u8>> for SignedCertificate {
-    fn from(value: Vec<u8>) -> Self {
-        let (t, start) = read_bytes(&value, 0);
-        let certificate = t.to_vec().into();
-        let (t, _) = read_bytes(&value, start);
+impl TryFrom<&[u8]> for SignedCertificate {
+    type Error = Error;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        let (t, start) = read_bytes(value, 0, "certificate")?;
+        let certificate = Certificate::try_from(t).map_err(|e| match e {
+            // Report offsets relative to the signed certificate, not the inner one
+            Error::Decode { field, offset } => Error::Decode {
+                field,
+                offset: offset + start - t.len(),
+            },
+            e => e,
+        })?;
+        let (t, end) = read_bytes(value, start, "signature")?;
         let signature = t.to_vec();
-        Self {
+
+        if end != value.len() {
+            return Err(Error::Decode {
+                field: "signed certificate",
+                offset: end,
+            });
+        }
+
+        Ok(Self {
             certificate,
             signature,
-        }
+        })
+    }
+}
+
+/// (certificate chain, root CA public key, root CA private key, end entity private key)
+pub type CertChain<S, R = S> = (
+    Vec<SignedCertificate>,
+    <R as SigningScheme>::VerifyingKey,
+    <R as SigningScheme>::SigningKey,
+    <S as SigningScheme>::SigningKey,
+);
+
+/// A scheme the handshake variants can be run with.
+pub trait TlsScheme: SigningScheme<SigningKey: Send, VerifyingKey: Send> + Send + Sized {
+    /// Declared in the certificates this scheme signs.
+    const ALGORITHM: AlgorithmId;
+
+    fn from_rng(rng: &mut dyn CryptoRngCore) -> Self;
+}
+
+impl TlsScheme for Eddsa {
+    const ALGORITHM: AlgorithmId = AlgorithmId::Ed25519;
+
+    fn from_rng(_: &mut dyn CryptoRngCore) -> Self {
+        Eddsa::new()
+    }
+}
+
+impl<C: Curve> TlsScheme for Ecdsa<C>
+where
+    ::ecdsa::SigningKey<C>: Send,
+    ::ecdsa::VerifyingKey<C>: Send,
+{
+    const ALGORITHM: AlgorithmId = C::ALGORITHM;
+
+    fn from_rng(_: &mut dyn CryptoRngCore) -> Self {
+        Ecdsa::new()
+    }
+}
+
+impl<const BITS: usize> TlsScheme for RsaPss<BITS> {
+    const ALGORITHM: AlgorithmId = match BITS {
+        2048 => AlgorithmId::RsaPss2048,
+        3072 => AlgorithmId::RsaPss3072,
+        4096 => AlgorithmId::RsaPss4096,
+        _ => panic!("no AlgorithmId for this modulus size"),
+    };
+
+    fn from_rng(rng: &mut dyn CryptoRngCore) -> Self {
+        RsaPss::from_rng(rng)
+    }
+}
+
+/// Always Falcon-512, the degree `from_rng` picks.
+impl TlsScheme for Falcon {
+    const ALGORITHM: AlgorithmId = AlgorithmId::Falcon512;
+
+    fn from_rng(rng: &mut dyn CryptoRngCore) -> Self {
+        Falcon::from_rng(Degree::F512, rng)
+    }
+}
+
+impl<P: mldsa::Params> TlsScheme for MlDsa<P>
+where
+    ml_dsa::SigningKey<P>: Send,
+    ml_dsa::VerifyingKey<P>: Send,
+    EncodedVerifyingKey<P>: Send,
+{
+    const ALGORITHM: AlgorithmId = P::ALGORITHM;
+
+    fn from_rng(rng: &mut dyn CryptoRngCore) -> Self {
+        MlDsa::from_rng(rng)
+    }
+}
+
+impl<P: Params> TlsScheme for SlhDsa<P>
+where
+    slh_dsa::SigningKey<P>: Send,
+    slh_dsa::VerifyingKey<P>: Send,
+{
+    const ALGORITHM: AlgorithmId = P::ALGORITHM;
+
+    fn from_rng(rng: &mut dyn CryptoRngCore) -> Self {
+        SlhDsa::from_rng(rng)
+    }
+}
+
+/// Ed25519 alongside a post-quantum scheme, for the hybrid handshake.
+impl<S: TlsScheme> TlsScheme for Composite<Eddsa, S> {
+    const ALGORITHM: AlgorithmId = match S::ALGORITHM {
+        AlgorithmId::Falcon512 => AlgorithmId::Ed25519Falcon512,
+        AlgorithmId::MlDsa65 => AlgorithmId::Ed25519MlDsa65,
+        _ => panic!("no AlgorithmId for Ed25519 with this scheme"),
+    };
+
+    fn from_rng(rng: &mut dyn CryptoRngCore) -> Self {
+        Composite::new(Eddsa::new(), S::from_rng(rng))
     }
 }
 
 pub trait Tls {
     type CX: Send;
     type SX: Send;
-    type S: SigningScheme;
+    type S: TlsScheme;
+    /// Scheme of the root CA, which signs its own certificate and the intermediate's.
+    type R: TlsScheme;
 
-    /// Returns (certificate chain, root CA public key, root CA private key, end entity private key)
-    fn make_cert_chain() -> (
-        Vec<SignedCertificate>,
-        <Self::S as SigningScheme>::VerifyingKey,
-        <Self::S as SigningScheme>::SigningKey,
-        <Self::S as SigningScheme>::SigningKey,
-    );
+    fn make_cert_chain(rng: &mut dyn CryptoRngCore) -> Result<CertChain<Self::S, Self::R>, Error>;
 
     fn _make_cert_chain(
+        root: &mut Self::R,
         scheme: &mut Self::S,
-    ) -> (
-        Vec<SignedCertificate>,
-        <Self::S as SigningScheme>::VerifyingKey,
-        <Self::S as SigningScheme>::SigningKey,
-        <Self::S as SigningScheme>::SigningKey,
-    ) {
-        let (sk_root, pk_root) = scheme.keygen();
-        let (sk_int, pk_int) = scheme.keygen();
-        let (sk_end, pk_end) = scheme.keygen();
+        rng: &mut dyn CryptoRngCore,
+    ) -> Result<CertChain<Self::S, Self::R>, Error> {
+        let (sk_root, pk_root) = root.keygen_with_rng(rng)?;
+        let (sk_int, pk_int) = scheme.keygen_with_rng(rng)?;
+        let (sk_end, pk_end) = scheme.keygen_with_rng(rng)?;
 
         let mut certs: Vec<SignedCertificate> = Vec::new();
 
         certs.push(
             Certificate {
+                algorithm: Self::S::ALGORITHM,
                 issuer_name: "intermediate-ca".to_string(),
                 subject_name: "end-entity".to_string(),
                 subject_pk: pk_end.to_bytes(),
             }
-            .sign(scheme, &sk_int),
+            .sign(scheme, &sk_int)?,
         );
 
         certs.push(
             Certificate {
+                algorithm: Self::R::ALGORITHM,
                 issuer_name: "root-ca".to_string(),
                 subject_name: "intermediate-ca".to_string(),
                 subject_pk: pk_int.to_bytes(),
             }
-            .sign(scheme, &sk_root),
+            .sign(root, &sk_root)?,
         );
 
         certs.push(
             Certificate {
+                algorithm: Self::R::ALGORITHM,
                 issuer_name: "self-signed".to_string(),
                 subject_name: "root-ca".to_string(),
                 subject_pk: pk_root.to_bytes(),
             }
-            .sign(scheme, &sk_root),
+            .sign(root, &sk_root)?,
         );
 
-        (certs, pk_root, sk_root, sk_end)
+        Ok((certs, pk_root, sk_root, sk_end))
     }
 
-    fn new() -> (Self::CX, Self::SX);
+    /// Sets up both endpoints, drawing every key and all signing randomness from `rng`.
+    fn new(rng: &mut dyn CryptoRngCore) -> Result<(Self::CX, Self::SX), Error>;
     fn client_transcript(client_ctx: &mut Self::CX, stream: &mut TcpStream);
     fn server_certificate(server_ctx: &mut Self::SX, stream: &mut TcpStream);
-    fn server_certificate_verify(server_ctx: &mut Self::SX, stream: &mut TcpStream);
+    fn server_certificate_verify(server_ctx: &mut Self::SX, stream: &mut TcpStream) -> bool;
     fn client_verify(client_ctx: &mut Self::CX, stream: &mut TcpStream) -> bool;
 }
+
+#[cfg(test)]
+mod tests {
+    use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
+
+    use super::*;
+    use crate::ecdsa::EcdsaP256;
+
+    fn certificate(algorithm: AlgorithmId) -> Certificate {
+        Certificate {
+            algorithm,
+            issuer_name: "issuer".to_string(),
+            subject_name: "subject".to_string(),
+            subject_pk: vec![1, 2, 3],
+        }
+    }
+
+    #[test]
+    fn certificates_round_trip() {
+        let mut rng = ChaCha20Rng::seed_from_u64(0);
+        let mut eddsa = Eddsa::new();
+        let (sk, pk) = eddsa.keygen_with_rng(&mut rng).unwrap();
+
+        let cert = certificate(AlgorithmId::Ed25519)
+            .sign(&mut eddsa, &sk)
+            .unwrap();
+        let decoded = SignedCertificate::try_from(&cert.to_bytes()[..]).unwrap();
+        assert_eq!(decoded.to_bytes(), cert.to_bytes());
+        assert_eq!(decoded.certificate.algorithm, AlgorithmId::Ed25519);
+        assert_eq!(verify_certificate(&mut eddsa, &pk, &decoded), Ok(()));
+    }
+
+    #[test]
+    fn the_declared_algorithm_is_enforced() {
+        let mut rng = ChaCha20Rng::seed_from_u64(1);
+        let mut eddsa = Eddsa::new();
+        let (sk, pk) = eddsa.keygen_with_rng(&mut rng).unwrap();
+
+        assert_eq!(
+            certificate(AlgorithmId::EcdsaP256)
+                .sign(&mut eddsa, &sk)
+                .err(),
+            Some(Error::AlgorithmMismatch {
+                expected: AlgorithmId::EcdsaP256,
+                found: AlgorithmId::Ed25519,
+            })
+        );
+
+        // Relabelling a certificate both fails the algorithm check and breaks the signature
+        let mut cert = certificate(AlgorithmId::Ed25519)
+            .sign(&mut eddsa, &sk)
+            .unwrap();
+        cert.certificate.algorithm = AlgorithmId::EcdsaP256;
+        assert_eq!(
+            verify_certificate(&mut eddsa, &pk, &cert),
+            Err(Error::AlgorithmMismatch {
+                expected: AlgorithmId::Ed25519,
+                found: AlgorithmId::EcdsaP256,
+            })
+        );
+
+        let mut ecdsa = EcdsaP256::new();
+        let (_, pk) = ecdsa.keygen_with_rng(&mut rng).unwrap();
+        assert!(verify_certificate(&mut ecdsa, &pk, &cert).is_err());
+    }
+
+    #[test]
+    fn unknown_algorithms_are_rejected() {
+        let mut bytes = certificate(AlgorithmId::Ed25519).to_bytes();
+        bytes[..2].copy_from_slice(&0xffffu16.to_be_bytes());
+        assert_eq!(
+            Certificate::try_from(&bytes[..]).err(),
+            Some(Error::UnknownAlgorithm)
+        );
+        assert_eq!(
+            Certificate::try_from(&bytes[..1]).err(),
+            Some(Error::Decode {
+                field: "algorithm",
+                offset: 0,
+            })
+        );
+    }
+}
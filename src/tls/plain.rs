@@ -1,45 +1,57 @@
-use std::net::TcpStream;
+use std::{marker::PhantomData, net::TcpStream};
 
-use crate::{
-    eddsa::Eddsa,
-    signing_scheme::{SigningScheme, ToBytes},
-};
+use rand_core::CryptoRngCore;
+
+use crate::{eddsa::Eddsa, signing_scheme::ToBytes, Error};
 
-use super::{read_bytes_stream, write_bytes_stream, SignedCertificate, Tls};
+use super::{
+    decode, read_bytes_stream, verify_certificate, verify_encoded, write_bytes_stream, CertChain,
+    SignedCertificate, Tls, TlsScheme,
+};
 
-pub struct PlainTls;
+/// Handshake with a classical scheme throughout, as the baseline for the post-quantum variants.
+pub struct PlainTls<S = Eddsa>(PhantomData<S>);
 
-pub struct ClientCtx {
-    pk_root: <Eddsa as SigningScheme>::VerifyingKey,
-    sk_root: <Eddsa as SigningScheme>::SigningKey,
+pub struct ClientCtx<S: TlsScheme> {
+    scheme: S,
+    pk_root: S::VerifyingKey,
+    sk_root: S::SigningKey,
 }
 
-pub struct ServerCtx {
+pub struct ServerCtx<S: TlsScheme> {
+    scheme: S,
     cert_chain: Vec<SignedCertificate>,
-    sk_end: <Eddsa as SigningScheme>::SigningKey,
+    sk_end: S::SigningKey,
 }
 
-impl Tls for PlainTls {
-    type CX = ClientCtx;
-    type SX = ServerCtx;
-    type S = Eddsa;
-
-    fn new() -> (ClientCtx, ServerCtx) {
-        let (cert_chain, pk_root, sk_root, sk_end) = Self::make_cert_chain();
-
-        (
-            ClientCtx { pk_root, sk_root },
-            ServerCtx { cert_chain, sk_end },
-        )
+impl<S: TlsScheme> Tls for PlainTls<S> {
+    type CX = ClientCtx<S>;
+    type SX = ServerCtx<S>;
+    type S = S;
+    type R = S;
+
+    fn new(rng: &mut dyn CryptoRngCore) -> Result<(ClientCtx<S>, ServerCtx<S>), Error> {
+        let (cert_chain, pk_root, sk_root, sk_end) = Self::make_cert_chain(rng)?;
+
+        let scheme1 = S::from_rng(rng);
+        let scheme2 = S::from_rng(rng);
+
+        Ok((
+            ClientCtx {
+                scheme: scheme1,
+                pk_root,
+                sk_root,
+            },
+            ServerCtx {
+                scheme: scheme2,
+                cert_chain,
+                sk_end,
+            },
+        ))
     }
 
-    fn make_cert_chain() -> (
-        Vec<SignedCertificate>,
-        <Self::S as SigningScheme>::VerifyingKey,
-        <Self::S as SigningScheme>::SigningKey,
-        <Self::S as SigningScheme>::SigningKey,
-    ) {
-        Self::_make_cert_chain(&mut Eddsa)
+    fn make_cert_chain(rng: &mut dyn CryptoRngCore) -> Result<CertChain<Self::S, Self::R>, Error> {
+        Self::_make_cert_chain(&mut S::from_rng(rng), &mut S::from_rng(rng), rng)
     }
 
     fn client_transcript(_: &mut Self::CX, stream: &mut TcpStream) {
@@ -53,39 +65,42 @@ impl Tls for PlainTls {
         }
     }
 
-    fn server_certificate_verify(ctx: &mut Self::SX, stream: &mut TcpStream) {
+    fn server_certificate_verify(ctx: &mut Self::SX, stream: &mut TcpStream) -> bool {
         let mut transcript_bytes = read_bytes_stream(stream, "server_certificate_verify");
         transcript_bytes.extend(ctx.cert_chain.iter().flat_map(|cert| cert.to_bytes()));
-        let signature = Eddsa.sign(&ctx.sk_end, &transcript_bytes);
+        let signature = ctx.scheme.sign(&ctx.sk_end, &transcript_bytes).unwrap();
         write_bytes_stream(stream, &signature.to_bytes()).unwrap();
+
+        true
     }
 
     fn client_verify(ctx: &mut Self::CX, stream: &mut TcpStream) -> bool {
         let mut certificate_chain: Vec<SignedCertificate> = Vec::new();
 
         for i in 0..3 {
-            let cert: SignedCertificate =
-                read_bytes_stream(stream, &format!("client_verify_cert_{i}")).into();
-            certificate_chain.push(cert.clone());
+            let bytes = read_bytes_stream(stream, &format!("client_verify_cert_{i}"));
+            match SignedCertificate::try_from(&bytes[..]) {
+                Ok(cert) => certificate_chain.push(cert),
+                Err(e) => {
+                    println!("[client_verify] Malformed certificate {i}: {e}");
+                    return false;
+                }
+            }
             // println!("[client_verify] Received certificate {i}");
         }
 
         // Verify root cert is signed by pk_root
 
-        let status = Eddsa.verify(
-            &ctx.pk_root,
-            &certificate_chain[2].certificate.to_bytes(),
-            &certificate_chain[2].signature.clone().into(),
-        );
+        let status = verify_certificate(&mut ctx.scheme, &ctx.pk_root, &certificate_chain[2]);
 
-        if !status {
-            println!("[client_verify] Root cert verification failed");
+        if let Err(e) = status {
+            println!("[client_verify] Root cert verification failed: {e}");
 
             let _expected_sig = certificate_chain[2]
                 .certificate
                 .clone()
-                .sign(&mut Eddsa, &ctx.sk_root)
-                .signature;
+                .sign(&mut ctx.scheme, &ctx.sk_root)
+                .map(|cert| cert.signature);
 
             println!("[client_verify] Expected signature {_expected_sig:?}");
             return false;
@@ -98,16 +113,13 @@ impl Tls for PlainTls {
         let mut m: Vec<u8> = "transcript-hash".into();
         m.extend(certificate_chain.iter().flat_map(|cert| cert.to_bytes()));
 
-        let status = Eddsa.verify(
-            &certificate_chain[0].certificate.subject_pk.clone().into(),
-            &m,
-            &certificate_verify.into(),
-        );
+        let status = decode(&certificate_chain[0].certificate.subject_pk)
+            .and_then(|pk| verify_encoded(&mut ctx.scheme, &pk, &m, &certificate_verify));
 
-        if !status {
-            println!("[client_verify] certificate verify check failed");
+        if let Err(e) = &status {
+            println!("[client_verify] certificate verify check failed: {e}");
         }
 
-        status
+        status.is_ok()
     }
 }
@@ -1,58 +1,60 @@
-use std::net::TcpStream;
+use std::{marker::PhantomData, net::TcpStream};
 
-use crate::{
-    falcon::{Degree, Falcon},
-    signing_scheme::{SigningScheme, ToBytes},
-};
+use rand_core::CryptoRngCore;
+
+use crate::{falcon::Falcon, signing_scheme::ToBytes, Error};
 
-use super::{read_bytes_stream, write_bytes_stream, SignedCertificate, Tls};
+use super::{
+    decode, read_bytes_stream, verify_certificate, verify_encoded, write_bytes_stream, CertChain,
+    SignedCertificate, Tls, TlsScheme,
+};
 
-pub struct PqcTls;
+/// Handshake signed with `S`, under a root CA whose certificates are signed with `R`.
+pub struct PqcTls<S = Falcon, R = S>(PhantomData<(S, R)>);
 
-pub struct ClientCtx {
-    falcon: Falcon,
-    pk_root: Vec<u8>,
-    sk_root: Vec<u8>,
+pub struct ClientCtx<S: TlsScheme, R: TlsScheme> {
+    scheme: S,
+    root: R,
+    pk_root: R::VerifyingKey,
+    sk_root: R::SigningKey,
 }
 
-pub struct ServerCtx {
-    falcon: Falcon,
+pub struct ServerCtx<S: TlsScheme> {
+    scheme: S,
     cert_chain: Vec<SignedCertificate>,
-    sk_end: Vec<u8>,
+    sk_end: S::SigningKey,
 }
 
-impl Tls for PqcTls {
-    type CX = ClientCtx;
-    type SX = ServerCtx;
-    type S = Falcon;
+impl<S: TlsScheme, R: TlsScheme> Tls for PqcTls<S, R> {
+    type CX = ClientCtx<S, R>;
+    type SX = ServerCtx<S>;
+    type S = S;
+    type R = R;
 
-    fn new() -> (ClientCtx, ServerCtx) {
-        let (cert_chain, pk_root, sk_root, sk_end) = Self::make_cert_chain();
+    fn new(rng: &mut dyn CryptoRngCore) -> Result<(ClientCtx<S, R>, ServerCtx<S>), Error> {
+        let (cert_chain, pk_root, sk_root, sk_end) = Self::make_cert_chain(rng)?;
 
-        let falcon1 = Falcon::new(Degree::F512, Some("seed1".as_bytes()));
-        let falcon2 = Falcon::new(Degree::F512, Some("seed2".as_bytes()));
+        let scheme1 = S::from_rng(rng);
+        let scheme2 = S::from_rng(rng);
+        let root = R::from_rng(rng);
 
-        (
+        Ok((
             ClientCtx {
-                falcon: falcon1,
+                scheme: scheme1,
+                root,
                 pk_root,
                 sk_root,
             },
             ServerCtx {
-                falcon: falcon2,
+                scheme: scheme2,
                 cert_chain,
                 sk_end,
             },
-        )
+        ))
     }
 
-    fn make_cert_chain() -> (
-        Vec<SignedCertificate>,
-        <Self::S as SigningScheme>::VerifyingKey,
-        <Self::S as SigningScheme>::SigningKey,
-        <Self::S as SigningScheme>::SigningKey,
-    ) {
-        Self::_make_cert_chain(&mut Falcon::new(Degree::F512, Some("seed".as_bytes())))
+    fn make_cert_chain(rng: &mut dyn CryptoRngCore) -> Result<CertChain<Self::S, Self::R>, Error> {
+        Self::_make_cert_chain(&mut R::from_rng(rng), &mut S::from_rng(rng), rng)
     }
 
     fn client_transcript(_: &mut Self::CX, stream: &mut TcpStream) {
@@ -66,39 +68,42 @@ impl Tls for PqcTls {
         }
     }
 
-    fn server_certificate_verify(ctx: &mut Self::SX, stream: &mut TcpStream) {
+    fn server_certificate_verify(ctx: &mut Self::SX, stream: &mut TcpStream) -> bool {
         let mut transcript_bytes = read_bytes_stream(stream, "server_certificate_verify");
         transcript_bytes.extend(ctx.cert_chain.iter().flat_map(|cert| cert.to_bytes()));
-        let signature = ctx.falcon.sign(&ctx.sk_end, &transcript_bytes);
+        let signature = ctx.scheme.sign(&ctx.sk_end, &transcript_bytes).unwrap();
         write_bytes_stream(stream, &signature.to_bytes()).unwrap();
+
+        true
     }
 
     fn client_verify(ctx: &mut Self::CX, stream: &mut TcpStream) -> bool {
         let mut certificate_chain: Vec<SignedCertificate> = Vec::new();
 
         for i in 0..3 {
-            let cert: SignedCertificate =
-                read_bytes_stream(stream, &format!("client_verify_cert_{i}")).into();
-            certificate_chain.push(cert.clone());
+            let bytes = read_bytes_stream(stream, &format!("client_verify_cert_{i}"));
+            match SignedCertificate::try_from(&bytes[..]) {
+                Ok(cert) => certificate_chain.push(cert),
+                Err(e) => {
+                    println!("[client_verify] Malformed certificate {i}: {e}");
+                    return false;
+                }
+            }
             // println!("[client_verify] Received certificate {i}");
         }
 
         // Verify root cert is signed by pk_root
 
-        let status = ctx.falcon.verify(
-            &ctx.pk_root,
-            &certificate_chain[2].certificate.to_bytes(),
-            &certificate_chain[2].signature.clone().into(),
-        );
+        let status = verify_certificate(&mut ctx.root, &ctx.pk_root, &certificate_chain[2]);
 
-        if !status {
-            println!("[client_verify] Root cert verification failed");
+        if let Err(e) = status {
+            println!("[client_verify] Root cert verification failed: {e}");
 
             let _expected_sig = certificate_chain[2]
                 .certificate
                 .clone()
-                .sign(&mut ctx.falcon, &ctx.sk_root)
-                .signature;
+                .sign(&mut ctx.root, &ctx.sk_root)
+                .map(|cert| cert.signature);
 
             println!("[client_verify] Expected signature {_expected_sig:?}");
             return false;
@@ -111,16 +116,13 @@ impl Tls for PqcTls {
         let mut m: Vec<u8> = "transcript-hash".into();
         m.extend(certificate_chain.iter().flat_map(|cert| cert.to_bytes()));
 
-        let status = ctx.falcon.verify(
-            &certificate_chain[0].certificate.subject_pk.clone().into(),
-            &m,
-            &certificate_verify.into(),
-        );
+        let status = decode(&certificate_chain[0].certificate.subject_pk)
+            .and_then(|pk| verify_encoded(&mut ctx.scheme, &pk, &m, &certificate_verify));
 
-        if !status {
-            println!("[client_verify] certificate verify check failed");
+        if let Err(e) = &status {
+            println!("[client_verify] certificate verify check failed: {e}");
         }
 
-        status
+        status.is_ok()
     }
 }
@@ -1,49 +1,52 @@
-use std::{collections::HashMap, net::TcpStream};
+use std::{collections::HashMap, marker::PhantomData, net::TcpStream};
 
-use crate::{
-    falcon::{Degree, Falcon},
-    signing_scheme::{SigningScheme, ToBytes},
-};
+use rand_core::CryptoRngCore;
+
+use crate::{falcon::Falcon, signing_scheme::ToBytes, Error};
 
-use super::{read_bytes_stream, write_bytes_stream, Certificate, SignedCertificate, Tls};
+use super::{
+    decode, read_bytes_stream, verify_certificate, verify_encoded, write_bytes_stream, CertChain,
+    Certificate, SignedCertificate, Tls, TlsScheme,
+};
 
-pub struct PqcWithCachingTls;
+pub struct PqcWithCachingTls<S = Falcon>(PhantomData<S>);
 
-pub struct ClientCtx {
-    falcon: Falcon,
-    pk_root: Vec<u8>,
-    pk_self: Vec<u8>,
-    sk_self: Vec<u8>,
+pub struct ClientCtx<S: TlsScheme> {
+    scheme: S,
+    pk_root: S::VerifyingKey,
+    pk_self: S::VerifyingKey,
+    sk_self: S::SigningKey,
     first: bool,
     id: u32,
 }
 
-pub struct ServerCtx {
-    falcon: Falcon,
+pub struct ServerCtx<S: TlsScheme> {
+    scheme: S,
     cert_chain: Vec<SignedCertificate>,
-    sk_end: Vec<u8>,
+    sk_end: S::SigningKey,
     cache: HashMap<u32, SignedCertificate>,
     first: bool,
     id: u32,
 }
 
-impl Tls for PqcWithCachingTls {
-    type CX = ClientCtx;
-    type SX = ServerCtx;
-    type S = Falcon;
+impl<S: TlsScheme> Tls for PqcWithCachingTls<S> {
+    type CX = ClientCtx<S>;
+    type SX = ServerCtx<S>;
+    type S = S;
+    type R = S;
 
-    fn new() -> (ClientCtx, ServerCtx) {
-        let (cert_chain, pk_root, _, sk_end) = Self::make_cert_chain();
+    fn new(rng: &mut dyn CryptoRngCore) -> Result<(ClientCtx<S>, ServerCtx<S>), Error> {
+        let (cert_chain, pk_root, _, sk_end) = Self::make_cert_chain(rng)?;
 
-        let mut falcon1 = Falcon::new(Degree::F512, Some("seed1".as_bytes()));
+        let mut scheme1 = S::from_rng(rng);
 
-        let (sk_self, pk_self) = falcon1.keygen();
+        let (sk_self, pk_self) = scheme1.keygen_with_rng(rng)?;
 
-        let falcon2 = Falcon::new(Degree::F512, Some("seed2".as_bytes()));
+        let scheme2 = S::from_rng(rng);
 
-        (
+        Ok((
             ClientCtx {
-                falcon: falcon1,
+                scheme: scheme1,
                 pk_root,
                 pk_self,
                 sk_self,
@@ -51,23 +54,18 @@ impl Tls for PqcWithCachingTls {
                 id: 1,
             },
             ServerCtx {
-                falcon: falcon2,
+                scheme: scheme2,
                 cert_chain,
                 sk_end,
                 cache: HashMap::new(),
                 first: true,
                 id: 0,
             },
-        )
+        ))
     }
 
-    fn make_cert_chain() -> (
-        Vec<SignedCertificate>,
-        <Self::S as SigningScheme>::VerifyingKey,
-        <Self::S as SigningScheme>::SigningKey,
-        <Self::S as SigningScheme>::SigningKey,
-    ) {
-        Self::_make_cert_chain(&mut Falcon::new(Degree::F512, Some("seed".as_bytes())))
+    fn make_cert_chain(rng: &mut dyn CryptoRngCore) -> Result<CertChain<Self::S, Self::R>, Error> {
+        Self::_make_cert_chain(&mut S::from_rng(rng), &mut S::from_rng(rng), rng)
     }
 
     fn client_transcript(ctx: &mut Self::CX, stream: &mut TcpStream) {
@@ -104,51 +102,74 @@ impl Tls for PqcWithCachingTls {
         }
     }
 
-    fn server_certificate_verify(ctx: &mut Self::SX, stream: &mut TcpStream) {
+    fn server_certificate_verify(ctx: &mut Self::SX, stream: &mut TcpStream) -> bool {
         let mut transcript_bytes =
             read_bytes_stream(stream, "server_certificate_verify_transcript");
 
         if ctx.first {
             transcript_bytes.extend(ctx.cert_chain.iter().flat_map(|cert| cert.to_bytes()));
         } else {
-            transcript_bytes.extend(ctx.cache.get(&ctx.id).unwrap().to_bytes());
+            match ctx.cache.get(&ctx.id) {
+                Some(cert) => transcript_bytes.extend(cert.to_bytes()),
+                None => {
+                    println!(
+                        "[server_certificate_verify] No cached certificate for {}",
+                        ctx.id
+                    );
+                    return false;
+                }
+            }
         }
 
-        let signature = ctx.falcon.sign(&ctx.sk_end, &transcript_bytes);
+        let signature = ctx.scheme.sign(&ctx.sk_end, &transcript_bytes).unwrap();
         write_bytes_stream(stream, &signature.to_bytes()).unwrap();
 
         if ctx.first {
             let client_cert_bytes =
                 read_bytes_stream(stream, "server_certificate_verify_client_cert");
-            let client_cert: SignedCertificate = client_cert_bytes.into();
-            ctx.cache.insert(ctx.id, client_cert);
+            match SignedCertificate::try_from(&client_cert_bytes[..]) {
+                Ok(client_cert) => {
+                    ctx.cache.insert(ctx.id, client_cert);
+                }
+                Err(e) => {
+                    println!("[server_certificate_verify] Malformed client certificate: {e}");
+                    return false;
+                }
+            }
         }
+
+        true
     }
 
     fn client_verify(ctx: &mut Self::CX, stream: &mut TcpStream) -> bool {
         let mut certificate_chain: Vec<SignedCertificate> = Vec::new();
 
         for i in 0..(if ctx.first { 3 } else { 1 }) {
-            let cert: SignedCertificate =
-                read_bytes_stream(stream, &format!("client_verify_cert_{i}")).into();
-            certificate_chain.push(cert.clone());
+            let bytes = read_bytes_stream(stream, &format!("client_verify_cert_{i}"));
+            match SignedCertificate::try_from(&bytes[..]) {
+                Ok(cert) => certificate_chain.push(cert),
+                Err(e) => {
+                    println!("[client_verify] Malformed certificate {i}: {e}");
+                    return false;
+                }
+            }
             // println!("[client_verify] Received certificate {i}");
         }
 
         // Verify root cert is signed by pk_root
 
-        let status = ctx.falcon.verify(
+        let status = verify_certificate(
+            &mut ctx.scheme,
             if ctx.first {
                 &ctx.pk_root
             } else {
                 &ctx.pk_self
             },
-            &certificate_chain.last().unwrap().certificate.to_bytes(),
-            &certificate_chain.last().unwrap().signature.clone().into(),
+            certificate_chain.last().unwrap(),
         );
 
-        if !status {
-            println!("[client_verify] Root cert verification failed");
+        if let Err(e) = status {
+            println!("[client_verify] Root cert verification failed: {e}");
             return false;
         }
 
@@ -159,24 +180,30 @@ impl Tls for PqcWithCachingTls {
         let mut m: Vec<u8> = "transcript-hash".into();
         m.extend(certificate_chain.iter().flat_map(|cert| cert.to_bytes()));
 
-        let status = ctx.falcon.verify(
-            &certificate_chain[0].certificate.subject_pk.clone().into(),
-            &m,
-            &certificate_verify.into(),
-        );
+        let status = decode(&certificate_chain[0].certificate.subject_pk)
+            .and_then(|pk| verify_encoded(&mut ctx.scheme, &pk, &m, &certificate_verify));
 
-        if !status {
-            println!("[client_verify] certificate verify check failed");
+        if let Err(e) = status {
+            println!("[client_verify] certificate verify check failed: {e}");
             return false;
         }
 
         if ctx.first {
             let cert = Certificate {
+                algorithm: S::ALGORITHM,
                 issuer_name: "client".to_string(),
                 subject_name: "end-entity".to_string(),
                 subject_pk: certificate_chain[0].certificate.subject_pk.clone(),
             }
-            .sign(&mut ctx.falcon, &ctx.sk_self);
+            .sign(&mut ctx.scheme, &ctx.sk_self);
+
+            let cert = match cert {
+                Ok(cert) => cert,
+                Err(e) => {
+                    println!("[client_verify] client certificate signing failed: {e}");
+                    return false;
+                }
+            };
 
             let cert_bytes = cert.to_bytes();
 
@@ -188,3 +215,51 @@ impl Tls for PqcWithCachingTls {
         true
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::Write,
+        net::{TcpListener, TcpStream},
+        thread,
+    };
+
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha20Rng;
+
+    use super::*;
+    use crate::eddsa::Eddsa;
+
+    /// A client certificate the server can't read fails the handshake rather than being skipped.
+    #[test]
+    fn malformed_client_certificates_are_refused() {
+        let mut rng = ChaCha20Rng::seed_from_u64(0);
+        let (_, mut sx) = PqcWithCachingTls::<Eddsa>::new(&mut rng).unwrap();
+        let certs = sx.cert_chain.len();
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let verified = thread::scope(|s| {
+            let server = s.spawn(|| {
+                let (mut stream, _) = listener.accept().unwrap();
+                PqcWithCachingTls::<Eddsa>::server_certificate(&mut sx, &mut stream);
+                stream.flush().unwrap();
+                PqcWithCachingTls::<Eddsa>::server_certificate_verify(&mut sx, &mut stream)
+            });
+
+            let mut stream = TcpStream::connect(addr).unwrap();
+            write_bytes_stream(&mut stream, b"first 1").unwrap();
+            write_bytes_stream(&mut stream, b"transcript-hash").unwrap();
+            // The chain, then the certificate verify signature
+            for i in 0..=certs {
+                read_bytes_stream(&mut stream, &format!("test_server_message_{i}"));
+            }
+            write_bytes_stream(&mut stream, &[0xff; 8]).unwrap();
+
+            server.join().unwrap()
+        });
+
+        assert!(!verified);
+        assert!(sx.cache.is_empty());
+    }
+}